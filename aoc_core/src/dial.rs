@@ -0,0 +1,47 @@
+// Turning the safe dial from day 1 of 2025. The dial shows 0 through 99 and
+// wraps around in both directions. These are const fns so the worked examples
+// can be checked at compile time.
+//
+// The position is worked out in i64, so any i32 distance turns without
+// overflowing and without looping once per lap.
+
+// Where the dial ends up from `current_position` once it has turned
+// `difference` clicks, negative being to the left.
+const fn wrap(current_position: i32, difference: i64) -> i32 {
+    (current_position as i64 + difference).rem_euclid(100) as i32
+}
+
+pub const fn turn_left(current_position: i32, distance: i32) -> i32 {
+    wrap(current_position, -(distance as i64))
+}
+
+pub const fn turn_right(current_position: i32, distance: i32) -> i32 {
+    wrap(current_position, distance as i64)
+}
+
+pub const fn turn_left_with_clicks(current_position: i32, distance: i32) -> (i32, i32) {
+    let mut zero_clicks = 0;
+
+    // starting on 0 doesn't count, only reaching it again 100 clicks later
+    if current_position == 0 {
+        zero_clicks = distance / 100;
+    } else {
+        let first_zero = current_position;
+        // landing exactly on the first 0 counts too
+        if first_zero <= distance {
+            zero_clicks += 1;
+
+            let remaining_after = distance - first_zero;
+            zero_clicks += remaining_after / 100;
+        }
+    }
+
+    (turn_left(current_position, distance), zero_clicks)
+}
+
+pub const fn turn_right_with_clicks(current_position: i32, distance: i32) -> (i32, i32) {
+    // every multiple of 100 passed is a click on 0, landing on one included
+    let zero_clicks = (current_position as i64 + distance as i64) / 100;
+
+    (turn_right(current_position, distance), zero_clicks as i32)
+}
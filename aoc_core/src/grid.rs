@@ -0,0 +1,146 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// A rectangular grid of ASCII cells stored row by row in a single buffer, so
+// looking up a cell is an index calculation rather than a walk along a line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl Grid {
+    pub fn from_lines(lines: &[String]) -> Grid {
+        let width = lines.first().map(|line| line.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for line in lines {
+            assert!(line.len() == width, "Grid rows must all be the same width");
+            cells.extend_from_slice(line.as_bytes());
+        }
+
+        Grid {
+            width,
+            height: lines.len(),
+            cells,
+        }
+    }
+
+    // Rows borrowed straight from the input, e.g. its lines as byte slices.
+    pub fn from_rows(rows: &[&[u8]]) -> Grid {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * rows.len());
+
+        for row in rows {
+            assert!(row.len() == width, "Grid rows must all be the same width");
+            cells.extend_from_slice(row);
+        }
+
+        Grid {
+            width,
+            height: rows.len(),
+            cells,
+        }
+    }
+
+    // `cells` holds `height` rows of `width` cells back to back, with no line breaks.
+    pub fn from_bytes(width: usize, height: usize, cells: &[u8]) -> Grid {
+        assert!(
+            cells.len() == width * height,
+            "Grid needs width * height cells"
+        );
+
+        Grid {
+            width,
+            height,
+            cells: cells.to_vec(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> u8 {
+        self.cells[row * self.width + column]
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, cell: u8) {
+        self.cells[row * self.width + column] = cell;
+    }
+
+    // How many of the (up to) eight cells around (row, column) hold `cell`.
+    pub fn count_adjacent(&self, row: usize, column: usize, cell: u8) -> u32 {
+        let mut count = 0;
+
+        for neighbour_row in row.saturating_sub(1)..=(row + 1).min(self.height - 1) {
+            for neighbour_column in column.saturating_sub(1)..=(column + 1).min(self.width - 1) {
+                let is_self = neighbour_row == row && neighbour_column == column;
+                if !is_self && self.get(neighbour_row, neighbour_column) == cell {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    pub fn to_lines(&self) -> Vec<String> {
+        self.cells
+            .chunks(self.width.max(1))
+            .map(|row| String::from_utf8_lossy(row).into_owned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn grid(rows: &[&str]) -> Grid {
+        Grid::from_lines(
+            &rows
+                .iter()
+                .map(|row| row.to_string())
+                .collect::<Vec<String>>(),
+        )
+    }
+
+    #[test]
+    fn test_count_adjacent() {
+        let grid = grid(&["@@.", "@.@", "..@"]);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        // corners only see three neighbours
+        assert_eq!(grid.count_adjacent(0, 0, b'@'), 2);
+        assert_eq!(grid.count_adjacent(2, 2, b'@'), 1);
+        // the middle sees all eight
+        assert_eq!(grid.count_adjacent(1, 1, b'@'), 5);
+        assert_eq!(grid.count_adjacent(1, 1, b'.'), 3);
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        assert_eq!(Grid::from_bytes(3, 2, b"@@.@.@"), grid(&["@@.", "@.@"]));
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert_eq!(Grid::from_rows(&[b"@@.", b"@.@"]), grid(&["@@.", "@.@"]));
+    }
+
+    #[test]
+    fn test_set_and_to_lines() {
+        let mut grid = grid(&["@@.", "@.@"]);
+        grid.set(1, 2, b'x');
+        assert_eq!(grid.get(1, 2), b'x');
+        assert_eq!(grid.to_lines(), vec!["@@.", "@.x"]);
+    }
+}
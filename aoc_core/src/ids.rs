@@ -0,0 +1,107 @@
+// Product ID checks for day 2 of 2025, done with arithmetic rather than by
+// comparing slices of the ID's string form. Being const fns, they can also
+// check the puzzle's examples at compile time.
+
+const fn count_digits(id: i64) -> u32 {
+    let mut digits = 0;
+    let mut remaining = id;
+    while remaining > 0 {
+        digits += 1;
+        remaining /= 10;
+    }
+    digits
+}
+
+// An ID is invalid when it is some sequence of digits repeated twice, i.e. the
+// first half times 10^half + 1.
+pub const fn is_valid_id_arithmetic(id: i64) -> bool {
+    let digits = count_digits(id);
+    if !digits.is_multiple_of(2) {
+        return true;
+    }
+
+    id % (10_i64.pow(digits / 2) + 1) != 0
+}
+
+// An ID is invalid when it is some sequence of digits repeated at least twice.
+// A block of `k` digits repeated is the block times 1 + 10^k + 10^2k + ..., so
+// it is enough to test whether the ID divides by that multiplier. The
+// multiplier is worked out in i128 since 10^19 doesn't fit an i64.
+pub const fn is_valid_id_part_two_arithmetic(id: i64) -> bool {
+    let digits = count_digits(id);
+
+    // a while loop as `for` isn't allowed in a const fn
+    let mut sequence_length = 1;
+    while sequence_length <= digits / 2 {
+        if digits.is_multiple_of(sequence_length) {
+            let block = 10_i128.pow(sequence_length);
+            let multiplier = (10_i128.pow(digits) - 1) / (block - 1);
+            if id as i128 % multiplier == 0 {
+                return false;
+            }
+        }
+        sequence_length += 1;
+    }
+
+    true
+}
+
+// Sum of the invalid IDs in start..=end, using the part one rule when
+// `repeated` is false and the part two rule when it is true.
+pub const fn sum_invalid_ids(start: i64, end: i64, repeated: bool) -> i64 {
+    let mut sum = 0;
+    let mut id = start;
+    while id <= end {
+        let is_valid = if repeated {
+            is_valid_id_part_two_arithmetic(id)
+        } else {
+            is_valid_id_arithmetic(id)
+        };
+        if !is_valid {
+            sum += id;
+        }
+        id += 1;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_id_arithmetic() {
+        for id in [11, 22, 99, 1010, 6464, 123123, 1188511885] {
+            assert!(!is_valid_id_arithmetic(id), "{} is an invalid ID", id);
+        }
+        for id in [101, 12345, 111, 1698522] {
+            assert!(is_valid_id_arithmetic(id), "{} is a valid ID", id);
+        }
+    }
+
+    #[test]
+    fn test_sum_invalid_ids() {
+        // 95-115 has 99 and, with the new rules, 111
+        assert_eq!(sum_invalid_ids(95, 115, false), 99);
+        assert_eq!(sum_invalid_ids(95, 115, true), 99 + 111);
+        assert_eq!(sum_invalid_ids(1698522, 1698528, true), 0);
+    }
+
+    #[test]
+    fn test_is_valid_id_part_two_arithmetic() {
+        for id in [12341234, 123123123, 1212121212, 1111111, 111] {
+            assert!(
+                !is_valid_id_part_two_arithmetic(id),
+                "{} is an invalid ID",
+                id
+            );
+        }
+        for id in [12345, 101, 1, 0] {
+            assert!(is_valid_id_part_two_arithmetic(id), "{} is a valid ID", id);
+        }
+
+        // 19 digits, as long as an i64 gets
+        assert!(!is_valid_id_part_two_arithmetic(1_111_111_111_111_111_111));
+        assert!(is_valid_id_part_two_arithmetic(i64::MAX));
+    }
+}
@@ -0,0 +1,101 @@
+// Picking batteries from a bank for day 3 of 2025. A bank is a line of digits
+// 1-9 and the joltage is the number formed by the digits turned on, in order.
+
+// Both of these are const fns, walking the bank's bytes with while loops
+// rather than iterators, so the examples can be checked at compile time.
+pub const fn get_joltage_from_bank(bank: &str) -> i32 {
+    get_joltage_from_digits(bank.as_bytes())
+}
+
+// The same over the bank's bytes, for callers holding a slice of the input.
+pub const fn get_joltage_from_digits(digits: &[u8]) -> i32 {
+    let mut first_height_digit = 0;
+    let mut second_height_digit = 0;
+
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = digits[i] - b'0';
+        let is_last_digit = i == digits.len() - 1;
+
+        if digit > first_height_digit && !is_last_digit {
+            first_height_digit = digit;
+            // resetting the 2nd digit to be the next one
+            second_height_digit = digits[i + 1] - b'0';
+        } else if digit > second_height_digit {
+            second_height_digit = digit;
+        }
+
+        i += 1;
+    }
+
+    first_height_digit as i32 * 10 + second_height_digit as i32
+}
+
+// The largest number that can be made from `batteries` digits of the bank
+// without reordering them. Builds the number as it goes rather than collecting
+// the digits into a string, so it needs no allocation.
+pub const fn get_max_joltage(bank: &str, batteries: usize) -> i64 {
+    get_max_joltage_from_digits(bank.as_bytes(), batteries)
+}
+
+pub const fn get_max_joltage_from_digits(digits: &[u8], batteries: usize) -> i64 {
+    let length = digits.len();
+
+    // short banks use every battery
+    let batteries = if length < batteries {
+        length
+    } else {
+        batteries
+    };
+
+    let mut joltage = 0;
+    let mut start_idx = 0;
+
+    // the digits we need to find
+    let mut position = 0;
+    while position < batteries {
+        let remaining_digits = batteries - position - 1;
+        let max_to_choose_from = length - remaining_digits;
+
+        let mut max_digit = 0;
+        let mut max_idx = start_idx;
+
+        let mut i = start_idx;
+        while i < max_to_choose_from {
+            if digits[i] > max_digit {
+                max_digit = digits[i];
+                max_idx = i;
+            }
+            i += 1;
+        }
+
+        joltage = joltage * 10 + (max_digit - b'0') as i64;
+        start_idx = max_idx + 1;
+        position += 1;
+    }
+
+    joltage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_max_joltage() {
+        assert_eq!(get_max_joltage("987654321111111", 12), 987654321111);
+        assert_eq!(get_max_joltage("234234234234278", 12), 434234234278);
+        assert_eq!(get_max_joltage("818181911112111", 2), 92);
+        // short banks use every battery
+        assert_eq!(get_max_joltage("1234", 12), 1234);
+    }
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(get_joltage_from_digits(b"818181911112111"), 92);
+        assert_eq!(
+            get_max_joltage_from_digits(b"818181911112111", 12),
+            888911112111
+        );
+    }
+}
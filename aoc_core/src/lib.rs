@@ -0,0 +1,12 @@
+// The pure puzzle logic, free of I/O so it builds without std (it only needs
+// an allocator for the grid and range helpers). The advent_of_code_2025 crate
+// reads inputs, runs and reports on top of this.
+#![no_std]
+
+extern crate alloc;
+
+pub mod dial;
+pub mod grid;
+pub mod ids;
+pub mod joltage;
+pub mod ranges;
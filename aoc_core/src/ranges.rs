@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+// Parses a range written as "start-end", e.g. "3-5" or "11-22".
+pub fn parse_range(range: &str) -> (i64, i64) {
+    let (start, end) = range.split_once('-').unwrap();
+    (start.parse::<i64>().unwrap(), end.parse::<i64>().unwrap())
+}
+
+// Sorts the ranges by start and folds each one into the previous range when
+// they overlap, so the result is sorted and no two ranges overlap.
+pub fn merge_ranges(ranges: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable();
+
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+// Number of values covered by ranges that don't overlap, e.g. from merge_ranges.
+pub fn count_covered(merged: &[(i64, i64)]) -> i64 {
+    merged.iter().map(|(start, end)| end - start + 1).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("3-5"), (3, 5));
+        assert_eq!(
+            parse_range("1188511880-1188511890"),
+            (1188511880, 1188511890)
+        );
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        let merged = merge_ranges(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(merged, vec![(3, 5), (10, 20)]);
+        assert_eq!(count_covered(&merged), 14);
+
+        // touching ranges are left alone, contained ranges disappear
+        assert_eq!(
+            merge_ranges(&[(5, 6), (1, 4), (2, 3)]),
+            vec![(1, 4), (5, 6)]
+        );
+        assert_eq!(merge_ranges(&[]), vec![]);
+    }
+}
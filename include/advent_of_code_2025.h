@@ -0,0 +1,57 @@
+/* Generated from src/ffi.rs, do not edit by hand. */
+#ifndef ADVENT_OF_CODE_2025_H
+#define ADVENT_OF_CODE_2025_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct AocDialTurn {
+    int32_t position;
+    int32_t zero_clicks;
+} AocDialTurn;
+
+typedef struct AocRange {
+    int64_t start;
+    int64_t end;
+} AocRange;
+
+/* Turns from `position` (0 to 99) by a `distance` of 0 or more. Gives */
+/* position -1 for anything else. */
+AocDialTurn aoc_turn_left_with_clicks(int32_t position, int32_t distance);
+
+/* Turns from `position` (0 to 99) by a `distance` of 0 or more. Gives */
+/* position -1 for anything else. */
+AocDialTurn aoc_turn_right_with_clicks(int32_t position, int32_t distance);
+
+/* true when the ID is some sequence of digits repeated exactly twice */
+bool aoc_is_doubled_id(int64_t id);
+
+/* true when the ID is some sequence of digits repeated at least twice */
+bool aoc_is_repeated_id(int64_t id);
+
+/* The largest joltage from turning on `batteries` of the `length` digits in `bank`. */
+int64_t aoc_max_joltage(const uint8_t* bank, size_t length, size_t batteries);
+
+/* Counts the rolls (@) with fewer than four rolls around them in a grid of */
+/* `width` * `height` cells stored row by row without line breaks. */
+int64_t aoc_count_accessible_rolls(const uint8_t* cells, size_t width, size_t height);
+
+/* Merges overlapping ranges in place, sorted by start, and returns how many */
+/* remain. SIZE_MAX, leaving the ranges as they were, when `ranges` is null or */
+/* a range ends before it starts. */
+size_t aoc_merge_ranges(AocRange* ranges, size_t length);
+
+/* How many values the ranges cover, counting overlaps once. -1 when a range */
+/* ends before it starts or the count doesn't fit an int64_t. */
+int64_t aoc_count_fresh_ids(const AocRange* ranges, size_t length);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
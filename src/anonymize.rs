@@ -0,0 +1,150 @@
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cli::Args;
+use crate::registry;
+use crate::runner;
+use crate::solution::Solution;
+use crate::util::generate::Rng;
+
+// Writes a copy of the day's input that gives the same answers without being
+// the same input, so a bug report can come with something to reproduce it.
+// Both inputs are solved and nothing is written unless the answers agree.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args
+        .day
+        .ok_or_else(|| String::from("anonymize needs a --day to rewrite"))?;
+    let solution = registry::find(args.year, day)
+        .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?;
+
+    let example = runner::find_example(&solution, args)?;
+    let lines = runner::read_lines(&solution, args, example)?;
+
+    // printed so the same copy can be made again
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64
+    });
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let anonymized = anonymize(&solution, &lines, seed, &parts)?;
+    eprintln!("Seed: {}", seed);
+    for (part, answer) in parts.iter().zip(&anonymized.answers) {
+        eprintln!("Part {}: {} from both inputs", part, answer);
+    }
+
+    let text = anonymized.lines.join("\n") + "\n";
+    match &args.output {
+        Some(path) => fs::write(path, text)
+            .map_err(|error| format!("Could not write {}: {}", path.display(), error)),
+        None => {
+            print!("{}", text);
+            Ok(())
+        }
+    }
+}
+
+pub struct Anonymized {
+    pub lines: Vec<String>,
+    // the answer to each of the checked parts, the same for both inputs
+    pub answers: Vec<String>,
+}
+
+// Rewrites `lines` with the day's anonymizer and checks `parts` still give
+// the same answers. An anonymizer that hands the input back as it was is an
+// error, since writing that out would publish the input it was meant to hide.
+pub fn anonymize(
+    solution: &Solution,
+    lines: &[String],
+    seed: u64,
+    parts: &[u8],
+) -> Result<Anonymized, String> {
+    let anonymize = solution
+        .anonymize
+        .ok_or_else(|| format!("{} day {} has no anonymizer", solution.year, solution.day))?;
+    let anonymized = anonymize(lines, &mut Rng::new(seed));
+    if anonymized == lines {
+        return Err(format!(
+            "The anonymized input is the same as the input, seed {}",
+            seed
+        ));
+    }
+
+    let mut answers = Vec::new();
+    for &part in parts {
+        let expected = (solution.part(part))(lines);
+        let answer = (solution.part(part))(&anonymized);
+        if answer != expected {
+            return Err(format!(
+                "The anonymized input gives {} for part {} instead of {}, seed {}",
+                answer, part, expected, seed
+            ));
+        }
+        answers.push(answer);
+    }
+
+    Ok(Anonymized {
+        lines: anonymized,
+        answers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Anonymize;
+
+    fn solution(anonymize: Option<Anonymize>) -> Solution {
+        Solution {
+            year: 2025,
+            day: 99,
+            title: "Counting",
+            part_one: |lines| lines.len().to_string(),
+            part_two: |lines| lines.first().cloned().unwrap_or_default(),
+            parse: |_, _| unreachable!("anonymize solves each part from the lines"),
+            explain: |_, _, _| String::new(),
+            debug: None,
+            stream: None,
+            solve_bytes: None,
+            anonymize,
+            generate: None,
+            lint: |_| Vec::new(),
+            examples: &[],
+        }
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let lines = ["a", "b", "c"].map(String::from);
+
+        // reversing keeps the line count but not the first line
+        let reversing = solution(Some(|lines, _| lines.iter().rev().cloned().collect()));
+        let anonymized = anonymize(&reversing, &lines, 1, &[1]).unwrap();
+        assert_eq!(anonymized.lines, vec!["c", "b", "a"]);
+        assert_eq!(anonymized.answers, vec!["3"]);
+        assert_eq!(
+            anonymize(&reversing, &lines, 1, &[1, 2]).err(),
+            Some(String::from(
+                "The anonymized input gives c for part 2 instead of a, seed 1"
+            ))
+        );
+
+        let unchanged = solution(Some(|lines, _| lines.to_vec()));
+        assert_eq!(
+            anonymize(&unchanged, &lines, 1, &[1]).err(),
+            Some(String::from(
+                "The anonymized input is the same as the input, seed 1"
+            ))
+        );
+
+        assert_eq!(
+            anonymize(&solution(None), &lines, 1, &[1]).err(),
+            Some(String::from("2025 day 99 has no anonymizer"))
+        );
+    }
+}
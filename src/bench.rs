@@ -0,0 +1,333 @@
+pub mod history;
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use crate::cli::Args;
+use crate::registry;
+use crate::runner;
+#[cfg(any(feature = "year_2025_day_4", feature = "year_2025_day_5"))]
+use crate::util::generate;
+#[cfg(any(
+    feature = "year_2025_day_2",
+    feature = "year_2025_day_4",
+    feature = "year_2025_day_5"
+))]
+use crate::util::generate::Rng;
+
+pub const DEFAULT_ITERATIONS: usize = 5;
+
+// Runs `f` `iterations` times and returns the median time along with the last result.
+pub fn median_time<T>(iterations: usize, mut f: impl FnMut() -> T) -> (T, Duration) {
+    let mut times = Vec::with_capacity(iterations);
+    let mut result = None;
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        result = Some(f());
+        times.push(start.elapsed());
+    }
+
+    times.sort_unstable();
+    (result.unwrap(), times[times.len() / 2])
+}
+
+// A naive implementation and its replacement, run against inputs of growing size.
+struct Pair {
+    name: &'static str,
+    // names the pair in the bench history
+    key: &'static str,
+    naive: &'static str,
+    optimized: &'static str,
+    // input size -> (naive time, optimized time), or an error if the two disagree
+    run: fn(usize, usize) -> Result<(Duration, Duration), String>,
+    sizes: &'static [usize],
+}
+
+fn pairs() -> Vec<Pair> {
+    vec![
+        #[cfg(feature = "year_2025_day_2")]
+        Pair {
+            name: "2025 day 2 repeated-digit IDs",
+            key: "day_2_repeated_ids",
+            naive: "day_2::is_valid_id_part_two",
+            optimized: "day_2::is_valid_id_part_two_arithmetic",
+            run: bench_repeated_ids,
+            sizes: &[10_000, 20_000, 40_000, 80_000, 160_000],
+        },
+        #[cfg(feature = "year_2025_day_4")]
+        Pair {
+            name: "2025 day 4 adjacent rolls",
+            key: "day_4_adjacent_rolls",
+            naive: "day_4::get_adjacent_cells_count",
+            optimized: "util::grid::Grid::count_adjacent",
+            run: bench_adjacent_rolls,
+            sizes: &[25, 50, 100, 200, 400],
+        },
+        #[cfg(feature = "year_2025_day_5")]
+        Pair {
+            name: "2025 day 5 range merging",
+            key: "day_5_range_merge",
+            naive: "day_5::parse_input_part_two",
+            optimized: "util::ranges::merge_ranges",
+            run: bench_range_merge,
+            sizes: &[250, 500, 1_000, 2_000, 4_000],
+        },
+    ]
+}
+
+pub fn run_pairs(args: &Args) -> Result<(), String> {
+    let iterations = args.iterations.unwrap_or(DEFAULT_ITERATIONS);
+    let mut run = history::Run::start();
+
+    for pair in pairs() {
+        println!("--- {} ---", pair.name);
+        println!("naive:     {}", pair.naive);
+        println!("optimized: {}", pair.optimized);
+        println!(
+            "{:>10} {:>14} {:>14} {:>9}",
+            "size", "naive", "optimized", "speedup"
+        );
+
+        for &size in pair.sizes {
+            let (naive, optimized) = (pair.run)(size, iterations)
+                .map_err(|error| format!("{}: {}", pair.name, error))?;
+
+            println!(
+                "{:>10} {:>14} {:>14} {:>8.1}x",
+                size,
+                format!("{:.2?}", naive),
+                format!("{:.2?}", optimized),
+                naive.as_secs_f64() / optimized.as_secs_f64().max(f64::EPSILON)
+            );
+
+            run.record(format!("pairs/{}/{}/naive", pair.key, size), naive);
+            run.record(format!("pairs/{}/{}/optimized", pair.key, size), optimized);
+        }
+
+        println!();
+    }
+
+    run.save()
+}
+
+// Times every part of the selected days against the real inputs.
+pub fn run_parts(args: &Args) -> Result<(), String> {
+    let iterations = args.iterations.unwrap_or(DEFAULT_ITERATIONS);
+    let solutions = match args.day {
+        Some(day) => vec![
+            registry::find(args.year, day)
+                .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?,
+        ],
+        None => registry::for_year(args.year),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut run = history::Run::start();
+    println!("{:>4} {:>5} {:>14}", "day", "part", "median");
+
+    for solution in &solutions {
+        let lines = runner::read_input(solution)?;
+        for &part in &parts {
+            let (_, median) = median_time(iterations, || solution.part(part)(&lines));
+            println!(
+                "{:>4} {:>5} {:>14}",
+                solution.day,
+                part,
+                format!("{:.2?}", median)
+            );
+            run.record(
+                format!("{}/day_{}/part_{}", solution.year, solution.day, part),
+                median,
+            );
+        }
+    }
+
+    println!();
+    run.save()
+}
+
+// Compares the latest recorded medians on this machine against the previous
+// run, or a baseline commit, and fails if any slowed down past the threshold.
+pub fn run_compare(args: &Args) -> Result<(), String> {
+    let threshold = args.threshold.unwrap_or(history::DEFAULT_THRESHOLD);
+    let machine = history::current_machine();
+    let records = history::load()?;
+
+    let comparisons = history::compare(&records, &machine, args.baseline.as_deref());
+    if comparisons.is_empty() {
+        return Err(match &args.baseline {
+            Some(baseline) => format!(
+                "Nothing to compare on {}: no benchmarks recorded at {}",
+                machine, baseline
+            ),
+            None => format!(
+                "Nothing to compare on {}: run a benchmark at least twice first",
+                machine
+            ),
+        });
+    }
+
+    println!(
+        "{:<45} {:>14} {:>14} {:>9}",
+        "benchmark", "before", "after", "change"
+    );
+
+    let mut regressions = 0;
+    for comparison in &comparisons {
+        let regressed = comparison.regressed(threshold);
+        if regressed {
+            regressions += 1;
+        }
+
+        println!(
+            "{:<45} {:>14} {:>14} {:>+8.1}%{}",
+            comparison.benchmark,
+            format!("{:.2?}", comparison.before.median),
+            format!("{:.2?}", comparison.after.median),
+            comparison.change(),
+            if regressed { "  regressed" } else { "" }
+        );
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} of {} benchmark(s) regressed by more than {}%",
+            regressions,
+            comparisons.len(),
+            threshold
+        ));
+    }
+
+    Ok(())
+}
+
+// Checks both implementations agree on the input before timing either of them.
+pub fn compare<T: PartialEq + Debug>(
+    iterations: usize,
+    naive: impl Fn() -> T,
+    optimized: impl Fn() -> T,
+) -> Result<(Duration, Duration), String> {
+    let expected = naive();
+    let actual = optimized();
+    if expected != actual {
+        return Err(format!(
+            "implementations disagree: naive gave {:?}, optimized gave {:?}",
+            expected, actual
+        ));
+    }
+
+    let (_, naive_time) = median_time(iterations, &naive);
+    let (_, optimized_time) = median_time(iterations, &optimized);
+
+    Ok((naive_time, optimized_time))
+}
+
+#[cfg(feature = "year_2025_day_2")]
+fn bench_repeated_ids(size: usize, iterations: usize) -> Result<(Duration, Duration), String> {
+    use crate::year_2025::day_2;
+
+    let mut rng = Rng::new(size as u64);
+    let ids = (0..size)
+        .map(|_| rng.range(1, 9_999_999_999))
+        .collect::<Vec<i64>>();
+
+    compare(
+        iterations,
+        || {
+            ids.iter()
+                .map(|&id| day_2::is_valid_id_part_two(id))
+                .collect::<Vec<bool>>()
+        },
+        || {
+            ids.iter()
+                .map(|&id| day_2::is_valid_id_part_two_arithmetic(id))
+                .collect::<Vec<bool>>()
+        },
+    )
+}
+
+#[cfg(feature = "year_2025_day_4")]
+fn bench_adjacent_rolls(size: usize, iterations: usize) -> Result<(Duration, Duration), String> {
+    use crate::util::grid::Grid;
+    use crate::year_2025::day_4;
+
+    let lines = generate::grid(&mut Rng::new(size as u64), size, size, 60, '@', '.');
+
+    compare(
+        iterations,
+        || {
+            let mut counts = Vec::with_capacity(size * size);
+            for row in 0..size {
+                for column in 0..size {
+                    counts.push(day_4::get_adjacent_cells_count(&lines, row, column));
+                }
+            }
+            counts
+        },
+        || {
+            // building the grid is part of the cost of the optimized version
+            let grid = Grid::from_lines(&lines);
+            let mut counts = Vec::with_capacity(size * size);
+            for row in 0..size {
+                for column in 0..size {
+                    counts.push(grid.count_adjacent(row, column, b'@'));
+                }
+            }
+            counts
+        },
+    )
+}
+
+#[cfg(feature = "year_2025_day_5")]
+fn bench_range_merge(size: usize, iterations: usize) -> Result<(Duration, Duration), String> {
+    use crate::util::ranges;
+    use crate::year_2025::day_5;
+
+    let fresh_ranges = generate::ranges(&mut Rng::new(size as u64), size, 1_000_000_000, 5_000_000);
+
+    compare(
+        iterations,
+        || {
+            let (count, mut merged) = day_5::parse_input_part_two(fresh_ranges.clone());
+            merged.sort_unstable();
+            (count, merged)
+        },
+        || {
+            let merged = ranges::merge_ranges(&fresh_ranges);
+            (ranges::count_covered(&merged), merged)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_time() {
+        let mut calls = 0;
+        let (result, _elapsed) = median_time(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(result, 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_compare_detects_disagreement() {
+        assert!(compare(1, || 1, || 1).is_ok());
+        assert!(compare(1, || 1, || 2).is_err());
+    }
+
+    #[test]
+    fn test_pairs_agree_on_small_inputs() {
+        for pair in pairs() {
+            assert!((pair.run)(pair.sizes[0], 1).is_ok(), "{}", pair.name);
+        }
+    }
+}
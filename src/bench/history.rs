@@ -0,0 +1,286 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config;
+
+// bench/history.txt unless paths.history says otherwise, one "<commit>
+// <machine> <timestamp> <benchmark> <nanos>" per line, appended to by every
+// bench run
+pub const HISTORY_PATH: &str = "bench/history.txt";
+// overrides the host name, for machines whose name changes between runs
+pub const MACHINE_ENV_VAR: &str = "AOC_BENCH_MACHINE";
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub commit: String,
+    pub machine: String,
+    pub timestamp: u64,
+    // e.g. 2025/day_4/part_2 or pairs/day_5_range_merge/1000/optimized
+    pub benchmark: String,
+    pub median: Duration,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.commit,
+            self.machine,
+            self.timestamp,
+            self.benchmark,
+            self.median.as_nanos()
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Record> {
+        let mut fields = line.split_whitespace();
+        let record = Record {
+            commit: fields.next()?.to_string(),
+            machine: fields.next()?.to_string(),
+            timestamp: fields.next()?.parse().ok()?,
+            benchmark: fields.next()?.to_string(),
+            median: Duration::from_nanos(fields.next()?.parse().ok()?),
+        };
+        fields.next().is_none().then_some(record)
+    }
+}
+
+// Collects the medians from one bench run so they share a commit, machine
+// and timestamp in the history.
+pub struct Run {
+    commit: String,
+    machine: String,
+    timestamp: u64,
+    records: Vec<Record>,
+}
+
+impl Run {
+    pub fn start() -> Self {
+        Run {
+            commit: current_commit(),
+            machine: current_machine(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0),
+            records: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, benchmark: String, median: Duration) {
+        self.records.push(Record {
+            commit: self.commit.clone(),
+            machine: self.machine.clone(),
+            timestamp: self.timestamp,
+            benchmark,
+            median,
+        });
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = &config::get().history_file;
+        let write_error =
+            |error: std::io::Error| format!("Could not write {}: {}", path.display(), error);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(write_error)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(write_error)?;
+        for record in &self.records {
+            writeln!(file, "{}", record.to_line()).map_err(write_error)?;
+        }
+
+        println!(
+            "Recorded {} benchmark(s) for {} on {} in {}",
+            self.records.len(),
+            self.commit,
+            self.machine,
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+pub fn load() -> Result<Vec<Record>, String> {
+    let path = &config::get().history_file;
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(format!("Could not read {}: {}", path.display(), error)),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            Record::from_line(line)
+                .ok_or_else(|| format!("{}:{}: malformed record", path.display(), index + 1))
+        })
+        .collect()
+}
+
+// The short hash of HEAD, marked dirty when there are uncommitted changes
+// since those timings don't belong to the commit alone.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => String::from("unknown"),
+    }
+}
+
+pub fn current_machine() -> String {
+    let name = env::var(MACHINE_ENV_VAR)
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .unwrap_or_default();
+
+    // records are space separated, so the name has to be one word
+    let name = name.split_whitespace().collect::<Vec<&str>>().join("-");
+    if name.is_empty() {
+        String::from("unknown")
+    } else {
+        name
+    }
+}
+
+pub struct Comparison {
+    pub benchmark: String,
+    pub before: Record,
+    pub after: Record,
+}
+
+impl Comparison {
+    // percentage change in the median, positive when it got slower
+    pub fn change(&self) -> f64 {
+        let before = self.before.median.as_secs_f64().max(f64::EPSILON);
+        (self.after.median.as_secs_f64() - before) / before * 100.0
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+// Pairs the latest measurement of every benchmark on `machine` with the one
+// before it, or with the latest one from the `baseline` commit (matched by
+// prefix) when there is one.
+pub fn compare(records: &[Record], machine: &str, baseline: Option<&str>) -> Vec<Comparison> {
+    let mut benchmarks = Vec::new();
+    for record in records.iter().filter(|record| record.machine == machine) {
+        if !benchmarks.contains(&&record.benchmark) {
+            benchmarks.push(&record.benchmark);
+        }
+    }
+
+    benchmarks
+        .into_iter()
+        .filter_map(|benchmark| {
+            let measurements = records
+                .iter()
+                .filter(|record| record.machine == machine && &record.benchmark == benchmark)
+                .collect::<Vec<&Record>>();
+            let (after, earlier) = measurements.split_last()?;
+
+            let before = match baseline {
+                Some(baseline) => earlier
+                    .iter()
+                    .rev()
+                    .find(|record| record.commit.starts_with(baseline))?,
+                None => earlier.last()?,
+            };
+
+            Some(Comparison {
+                benchmark: benchmark.clone(),
+                before: (*before).clone(),
+                after: (*after).clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, machine: &str, benchmark: &str, millis: u64) -> Record {
+        Record {
+            commit: commit.to_string(),
+            machine: machine.to_string(),
+            timestamp: 1_700_000_000,
+            benchmark: benchmark.to_string(),
+            median: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let original = record("abc1234", "laptop", "2025/day_4/part_2", 12);
+        assert_eq!(Record::from_line(&original.to_line()), Some(original));
+        assert_eq!(
+            Record::from_line("abc1234 laptop 17 2025/day_1/part_1"),
+            None
+        );
+        assert_eq!(
+            Record::from_line("abc1234 laptop x 2025/day_1/part_1 5"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compare_with_previous_run() {
+        let records = [
+            record("aaa", "laptop", "2025/day_4/part_2", 10),
+            record("aaa", "desktop", "2025/day_4/part_2", 1),
+            record("bbb", "laptop", "2025/day_4/part_2", 20),
+            record("bbb", "laptop", "2025/day_5/part_1", 5),
+            record("ccc", "laptop", "2025/day_4/part_2", 21),
+        ];
+
+        let comparisons = compare(&records, "laptop", None);
+        // day 5 has only been measured once, so there is nothing to compare it with
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].before.commit, "bbb");
+        assert_eq!(comparisons[0].after.commit, "ccc");
+        assert!((comparisons[0].change() - 5.0).abs() < 1e-9);
+        assert!(!comparisons[0].regressed(DEFAULT_THRESHOLD));
+    }
+
+    #[test]
+    fn test_compare_with_baseline() {
+        let records = [
+            record("aaa1111", "laptop", "2025/day_4/part_2", 10),
+            record("bbb2222", "laptop", "2025/day_4/part_2", 20),
+            record("ccc3333", "laptop", "2025/day_4/part_2", 21),
+        ];
+
+        let comparisons = compare(&records, "laptop", Some("aaa"));
+        assert_eq!(comparisons[0].before.commit, "aaa1111");
+        assert!((comparisons[0].change() - 110.0).abs() < 1e-9);
+        assert!(comparisons[0].regressed(DEFAULT_THRESHOLD));
+
+        assert!(compare(&records, "laptop", Some("fff")).is_empty());
+    }
+}
@@ -0,0 +1,485 @@
+use std::path::PathBuf;
+
+pub const DEFAULT_YEAR: u16 = 2025;
+
+pub const USAGE: &str = "Usage:
+  advent_of_code_2025 [run] [--year YEAR] [--day DAY] [--part PART] [--explain] [--example [N]]
+                      [--stream | --bytes] [--input PATH] [--no-cache]
+  advent_of_code_2025 report [--year YEAR] [--html] [--redact] [--output PATH]
+  advent_of_code_2025 debug --day DAY [--part PART] [--example [N]] [--input PATH]
+                            [--break COND]...
+  advent_of_code_2025 lint [--year YEAR] [--day DAY] [--input PATH]
+  advent_of_code_2025 bench parts [--year YEAR] [--day DAY] [--part PART] [--iterations N]
+  advent_of_code_2025 bench pairs [--iterations N]
+  advent_of_code_2025 bench compare [--baseline COMMIT] [--threshold PERCENT]
+  advent_of_code_2025 encrypt-inputs [--year YEAR]
+  advent_of_code_2025 decrypt-inputs [--year YEAR]
+  advent_of_code_2025 serve [--port PORT] [--timeout SECS] [--max-body BYTES]
+  advent_of_code_2025 tui [--year YEAR] [--day DAY]
+  advent_of_code_2025 anonymize --day DAY [--part PART] [--example [N]] [--input PATH]
+                                [--seed SEED] [--output PATH]
+  advent_of_code_2025 stats [--input PATH] [--output PATH]
+  advent_of_code_2025 complexity [--year YEAR] [--day DAY] [--part PART] [--iterations N]
+
+Every command reads its defaults from aoc.toml (or --config PATH), which
+AOC_* environment variables override and flags override in turn.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run,
+    Report,
+    Lint,
+    Debug,
+    Bench(BenchTarget),
+    EncryptInputs,
+    DecryptInputs,
+    Serve,
+    Tui,
+    Anonymize,
+    Stats,
+    Complexity,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BenchTarget {
+    // every part against the real inputs
+    Parts,
+    // naive and optimized implementations side by side
+    Pairs,
+    // the latest recorded run against an earlier one
+    Compare,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    pub year: u16,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub explain: bool,
+    // which worked example to run instead of the real input, counting from 1
+    pub example: Option<usize>,
+    // read the input a line at a time instead of all at once
+    pub stream: bool,
+    // hand the solver the input's bytes, memory-mapped with the mmap feature
+    pub bytes: bool,
+    // parse the input even when there is a cached parse of it
+    pub no_cache: bool,
+    // conditions like "removed < 5" the debugger stops at
+    pub breakpoints: Vec<String>,
+    pub html: bool,
+    pub redact: bool,
+    pub output: Option<PathBuf>,
+    pub input: Option<PathBuf>,
+    pub iterations: Option<usize>,
+    pub baseline: Option<String>,
+    pub threshold: Option<f64>,
+    pub port: Option<u16>,
+    pub timeout: Option<u64>,
+    pub max_body: Option<usize>,
+    // the anonymizer's random choices, a fresh one each run unless given
+    pub seed: Option<u64>,
+    // aoc.toml unless given
+    pub config: Option<PathBuf>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            command: Command::Run,
+            year: DEFAULT_YEAR,
+            day: None,
+            part: None,
+            explain: false,
+            example: None,
+            stream: false,
+            bytes: false,
+            no_cache: false,
+            breakpoints: Vec::new(),
+            html: false,
+            redact: false,
+            output: None,
+            input: None,
+            iterations: None,
+            baseline: None,
+            threshold: None,
+            port: None,
+            timeout: None,
+            max_body: None,
+            seed: None,
+            config: None,
+        }
+    }
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Args, String> {
+    parse_args_with(args, Args::default())
+}
+
+// Parses the flags on top of `defaults`, e.g. the ones from the config file.
+pub fn parse_args_with<I: Iterator<Item = String>>(
+    args: I,
+    defaults: Args,
+) -> Result<Args, String> {
+    let mut parsed = defaults;
+
+    let mut args = args.peekable();
+
+    if let Some(command) = args.next_if(|arg| !arg.starts_with("--")) {
+        parsed.command = match command.as_str() {
+            "run" => Command::Run,
+            "report" => Command::Report,
+            "lint" => Command::Lint,
+            "debug" => Command::Debug,
+            "encrypt-inputs" => Command::EncryptInputs,
+            "decrypt-inputs" => Command::DecryptInputs,
+            "serve" => Command::Serve,
+            "tui" => Command::Tui,
+            "anonymize" => Command::Anonymize,
+            "stats" => Command::Stats,
+            "complexity" => Command::Complexity,
+            "bench" => match args.next_if(|arg| !arg.starts_with("--")).as_deref() {
+                Some("parts") => Command::Bench(BenchTarget::Parts),
+                Some("pairs") => Command::Bench(BenchTarget::Pairs),
+                Some("compare") => Command::Bench(BenchTarget::Compare),
+                Some(target) => return Err(format!("Unknown bench target: {}", target)),
+                None => {
+                    return Err(String::from(
+                        "Missing bench target, expected parts, pairs or compare",
+                    ));
+                }
+            },
+            _ => return Err(format!("Unknown command: {}", command)),
+        };
+    }
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", flag))
+        };
+
+        match flag.as_str() {
+            "--year" => parsed.year = parse_number(&flag, &value()?)?,
+            "--day" => parsed.day = Some(parse_number(&flag, &value()?)?),
+            "--part" => {
+                let part = parse_number(&flag, &value()?)?;
+                if part != 1 && part != 2 {
+                    return Err(format!("Invalid part: {}", part));
+                }
+                parsed.part = Some(part);
+            }
+            "--explain" => parsed.explain = true,
+            "--stream" => parsed.stream = true,
+            "--bytes" => parsed.bytes = true,
+            "--no-cache" => parsed.no_cache = true,
+            "--break" => parsed.breakpoints.push(value()?),
+            "--example" => {
+                // the number is optional, most days only have the one example
+                let example = match args.next_if(|arg| !arg.starts_with("--")) {
+                    Some(example) => parse_number(&flag, &example)?,
+                    None => 1,
+                };
+                if example == 0 {
+                    return Err(String::from("Examples are numbered from 1"));
+                }
+                parsed.example = Some(example);
+            }
+            "--html" => parsed.html = true,
+            "--redact" => parsed.redact = true,
+            "--output" => parsed.output = Some(PathBuf::from(value()?)),
+            "--input" => parsed.input = Some(PathBuf::from(value()?)),
+            "--iterations" => parsed.iterations = Some(parse_number(&flag, &value()?)?),
+            "--baseline" => parsed.baseline = Some(value()?),
+            "--threshold" => parsed.threshold = Some(parse_number(&flag, &value()?)?),
+            "--port" => parsed.port = Some(parse_number(&flag, &value()?)?),
+            "--timeout" => parsed.timeout = Some(parse_number(&flag, &value()?)?),
+            "--max-body" => parsed.max_body = Some(parse_number(&flag, &value()?)?),
+            "--seed" => parsed.seed = Some(parse_number(&flag, &value()?)?),
+            "--config" => parsed.config = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("Unknown flag: {}", flag)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse::<T>()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &str) -> Result<Args, String> {
+        parse_args(input.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args_defaults() {
+        assert_eq!(
+            args(""),
+            Ok(Args {
+                command: Command::Run,
+                year: 2025,
+                day: None,
+                part: None,
+                explain: false,
+                example: None,
+                stream: false,
+                bytes: false,
+                no_cache: false,
+                breakpoints: Vec::new(),
+                html: false,
+                redact: false,
+                output: None,
+                input: None,
+                iterations: None,
+                baseline: None,
+                threshold: None,
+                port: None,
+                timeout: None,
+                max_body: None,
+                seed: None,
+                config: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_year_day_part() {
+        assert_eq!(
+            args("run --year 2024 --day 5 --part 2"),
+            Ok(Args {
+                year: 2024,
+                day: Some(5),
+                part: Some(2),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_explain() {
+        assert_eq!(
+            args("--day 1 --part 2 --explain"),
+            Ok(Args {
+                day: Some(1),
+                part: Some(2),
+                explain: true,
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_report() {
+        assert_eq!(
+            args("report --html --redact --output report.html"),
+            Ok(Args {
+                command: Command::Report,
+                html: true,
+                redact: true,
+                output: Some(PathBuf::from("report.html")),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_example() {
+        assert_eq!(
+            args("--day 4 --example"),
+            Ok(Args {
+                day: Some(4),
+                example: Some(1),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            args("--example 2 --part 1"),
+            Ok(Args {
+                part: Some(1),
+                example: Some(2),
+                ..Args::default()
+            })
+        );
+        assert!(args("--example 0").is_err());
+        assert!(args("--example two").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_stream() {
+        assert_eq!(
+            args("--day 5 --stream --input huge.txt"),
+            Ok(Args {
+                day: Some(5),
+                stream: true,
+                input: Some(PathBuf::from("huge.txt")),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            args("--day 5 --no-cache"),
+            Ok(Args {
+                day: Some(5),
+                no_cache: true,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            args("--day 4 --bytes"),
+            Ok(Args {
+                day: Some(4),
+                bytes: true,
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_lint() {
+        assert_eq!(
+            args("lint --day 4 --input generated.txt"),
+            Ok(Args {
+                command: Command::Lint,
+                day: Some(4),
+                input: Some(PathBuf::from("generated.txt")),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_debug() {
+        assert_eq!(
+            args("debug --day 1 --break zero_clicks>2 --break line==17"),
+            Ok(Args {
+                command: Command::Debug,
+                day: Some(1),
+                breakpoints: vec![String::from("zero_clicks>2"), String::from("line==17")],
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_config() {
+        assert_eq!(
+            args("report --config ci.toml"),
+            Ok(Args {
+                command: Command::Report,
+                config: Some(PathBuf::from("ci.toml")),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        assert_eq!(
+            args("bench pairs --iterations 3"),
+            Ok(Args {
+                command: Command::Bench(BenchTarget::Pairs),
+                iterations: Some(3),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            args("bench parts --day 4"),
+            Ok(Args {
+                command: Command::Bench(BenchTarget::Parts),
+                day: Some(4),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            args("bench compare --baseline abc1234 --threshold 2.5"),
+            Ok(Args {
+                command: Command::Bench(BenchTarget::Compare),
+                baseline: Some(String::from("abc1234")),
+                threshold: Some(2.5),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_serve() {
+        assert_eq!(
+            args("serve --port 9000 --timeout 3 --max-body 4096"),
+            Ok(Args {
+                command: Command::Serve,
+                port: Some(9000),
+                timeout: Some(3),
+                max_body: Some(4096),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_tui() {
+        assert_eq!(
+            args("tui --day 4"),
+            Ok(Args {
+                command: Command::Tui,
+                day: Some(4),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_anonymize() {
+        assert_eq!(
+            args("anonymize --day 5 --seed 42 --output shareable.txt"),
+            Ok(Args {
+                command: Command::Anonymize,
+                day: Some(5),
+                seed: Some(42),
+                output: Some(PathBuf::from("shareable.txt")),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_stats() {
+        assert_eq!(
+            args("stats --input exports/leaderboard.json"),
+            Ok(Args {
+                command: Command::Stats,
+                input: Some(PathBuf::from("exports/leaderboard.json")),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_complexity() {
+        assert_eq!(
+            args("complexity --day 3 --part 2 --iterations 3"),
+            Ok(Args {
+                command: Command::Complexity,
+                day: Some(3),
+                part: Some(2),
+                iterations: Some(3),
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(args("--day").is_err(), "missing value");
+        assert!(args("--day five").is_err(), "not a number");
+        assert!(args("--part 3").is_err(), "only two parts");
+        assert!(args("--verbose").is_err(), "unknown flag");
+        assert!(args("fly").is_err(), "unknown command");
+        assert!(args("bench").is_err(), "missing bench target");
+    }
+}
@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use crate::bench::{self, DEFAULT_ITERATIONS};
+use crate::cli::Args;
+use crate::registry;
+use crate::solution::Solution;
+use crate::util::generate::Rng;
+
+// the first generated input's size in bytes, doubled for each step after it
+pub const START_SIZE: usize = 4_000;
+pub const STEPS: usize = 8;
+// the largest sizes the exponent is fitted to, since the smallest ones time
+// little but the fixed cost of a call
+const FIT_SAMPLES: usize = 4;
+// a part whose median takes longer than this stops growing, so a quadratic
+// part doesn't hold the rest up for minutes
+const SLOW_STEP: Duration = Duration::from_secs(1);
+
+// Times each part against generated inputs of doubling size and fits how
+// the time grows with the input, e.g. part 2 ≈ O(n^2). A part that ought to
+// be linear but comes out quadratic is rescanning something it shouldn't.
+pub fn run(args: &Args) -> Result<(), String> {
+    let iterations = args.iterations.unwrap_or(DEFAULT_ITERATIONS);
+    let solutions = match args.day {
+        Some(day) => vec![
+            registry::find(args.year, day)
+                .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?,
+        ],
+        None => registry::for_year(args.year)
+            .into_iter()
+            .filter(|solution| solution.generate.is_some())
+            .collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let sizes = (0..STEPS)
+        .map(|step| START_SIZE << step)
+        .collect::<Vec<usize>>();
+
+    for solution in &solutions {
+        println!(
+            "--- {} day {}: {} ---",
+            solution.year, solution.day, solution.title
+        );
+        let growths = measure(solution, &parts, &sizes, iterations)?;
+
+        print!("{:>10}", "bytes");
+        for growth in &growths {
+            print!(" {:>14}", format!("part {}", growth.part));
+        }
+        println!();
+        for (step, &(bytes, _)) in growths[0].samples.iter().enumerate() {
+            print!("{:>10}", bytes);
+            for growth in &growths {
+                let time = growth
+                    .samples
+                    .get(step)
+                    .map(|(_, time)| format!("{:.2?}", time))
+                    .unwrap_or_default();
+                print!(" {:>14}", time);
+            }
+            println!();
+        }
+
+        for growth in &growths {
+            match growth.exponent() {
+                Some(exponent) => println!(
+                    "part {} ≈ {} (fitted n^{:.2})",
+                    growth.part,
+                    big_o(exponent),
+                    exponent
+                ),
+                None => println!("part {}: too slow to time twice", growth.part),
+            }
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+// How one part's median time grows with the input.
+pub struct Growth {
+    pub part: u8,
+    // the input's size in bytes and the median time, smallest first
+    pub samples: Vec<(usize, Duration)>,
+}
+
+impl Growth {
+    // The k in time ≈ c·n^k, by least squares over the logs of the largest
+    // samples. None with fewer than two.
+    pub fn exponent(&self) -> Option<f64> {
+        let samples = &self.samples[self.samples.len().saturating_sub(FIT_SAMPLES)..];
+        if samples.len() < 2 {
+            return None;
+        }
+
+        let points = samples
+            .iter()
+            .map(|&(bytes, time)| {
+                // a zero time has no log
+                let seconds = time.as_secs_f64().max(1e-9);
+                ((bytes as f64).ln(), seconds.ln())
+            })
+            .collect::<Vec<(f64, f64)>>();
+        let count = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+        let covariance = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>();
+        let variance = points
+            .iter()
+            .map(|(x, _)| (x - mean_x).powi(2))
+            .sum::<f64>();
+        Some(covariance / variance)
+    }
+}
+
+// Times `parts` against the day's generated input at each of `sizes`, each
+// input made once from a seed of its size so runs are repeatable.
+pub fn measure(
+    solution: &Solution,
+    parts: &[u8],
+    sizes: &[usize],
+    iterations: usize,
+) -> Result<Vec<Growth>, String> {
+    let generate = solution.generate.ok_or_else(|| {
+        format!(
+            "{} day {} has no input generator",
+            solution.year, solution.day
+        )
+    })?;
+
+    let mut growths = parts
+        .iter()
+        .map(|&part| Growth {
+            part,
+            samples: Vec::new(),
+        })
+        .collect::<Vec<Growth>>();
+
+    for &size in sizes {
+        let is_slow = |growth: &Growth| {
+            growth
+                .samples
+                .last()
+                .is_some_and(|&(_, time)| time > SLOW_STEP)
+        };
+        if growths.iter().all(is_slow) {
+            break;
+        }
+
+        let lines = generate(size, &mut Rng::new(size as u64));
+        let bytes = lines.iter().map(|line| line.len() + 1).sum::<usize>();
+
+        for growth in growths.iter_mut().filter(|growth| !is_slow(growth)) {
+            let (_, median) = bench::median_time(iterations, || solution.part(growth.part)(&lines));
+            growth.samples.push((bytes, median));
+        }
+    }
+
+    Ok(growths)
+}
+
+// The exponent to the nearest half as big-O, e.g. O(n^1.5).
+pub fn big_o(exponent: f64) -> String {
+    let rounded = (exponent * 2.0).round() / 2.0;
+    if rounded <= 0.0 {
+        String::from("O(1)")
+    } else if rounded == 1.0 {
+        String::from("O(n)")
+    } else {
+        format!("O(n^{})", rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn growth(exponent: f64) -> Growth {
+        Growth {
+            part: 1,
+            samples: [1_000, 2_000, 4_000, 8_000, 16_000]
+                .iter()
+                .map(|&bytes| {
+                    let nanos = (bytes as f64).powf(exponent);
+                    (bytes, Duration::from_nanos(nanos as u64))
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_exponent() {
+        for exponent in [1.0, 1.5, 2.0, 3.0] {
+            let fitted = growth(exponent).exponent().unwrap();
+            assert!(
+                (fitted - exponent).abs() < 0.01,
+                "{} for {}",
+                fitted,
+                exponent
+            );
+        }
+
+        let mut too_slow = growth(2.0);
+        too_slow.samples.truncate(1);
+        assert_eq!(too_slow.exponent(), None);
+    }
+
+    #[test]
+    fn test_big_o() {
+        assert_eq!(big_o(0.1), "O(1)");
+        assert_eq!(big_o(1.07), "O(n)");
+        assert_eq!(big_o(1.4), "O(n^1.5)");
+        assert_eq!(big_o(1.93), "O(n^2)");
+        assert_eq!(big_o(2.9), "O(n^3)");
+    }
+
+    #[test]
+    fn test_generated_inputs_lint_clean() {
+        for solution in registry::solutions() {
+            let Some(generate) = solution.generate else {
+                continue;
+            };
+            let lines = generate(2_000, &mut Rng::new(1));
+            let bytes = lines.iter().map(|line| line.len() + 1).sum::<usize>();
+            assert!(
+                (1_000..4_000).contains(&bytes),
+                "day {} made {} bytes",
+                solution.day,
+                bytes
+            );
+            assert!(
+                (solution.lint)(&lines).is_empty(),
+                "day {} generated an input that doesn't lint",
+                solution.day
+            );
+        }
+    }
+
+    #[test]
+    fn test_measure() {
+        for solution in registry::solutions() {
+            if solution.generate.is_none() {
+                continue;
+            }
+            let growths = measure(&solution, &[1, 2], &[2_000, 4_000], 1).unwrap();
+            assert_eq!(growths.len(), 2);
+            assert!(growths.iter().all(|growth| growth.samples.len() == 2));
+            assert!(growths[0].samples[0].0 < growths[0].samples[1].0);
+        }
+    }
+}
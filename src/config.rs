@@ -0,0 +1,477 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::bench::{self, history};
+use crate::cli::{self, Args};
+use crate::util::toml::{self, Value};
+use crate::util::{cipher, fixtures, input};
+
+// aoc.toml in the directory the binary runs from, unless --config says otherwise
+pub const CONFIG_PATH: &str = "aoc.toml";
+// where parsed inputs are cached, see crate::model
+pub const CACHE_DIR: &str = ".cache";
+// a private leaderboard's JSON export, read by crate::stats
+pub const LEADERBOARD_FILE: &str = "leaderboard.json";
+// prefix of the environment variables that override the file, e.g.
+// AOC_INPUT_DIR or AOC_2025_DAY_4_THRESHOLD
+pub const ENV_PREFIX: &str = "AOC_";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+// Settings layered from lowest to highest precedence: the defaults below,
+// aoc.toml, then AOC_* environment variables. Command line flags go on top
+// of that through `defaults`.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub input_dir: PathBuf,
+    pub answers_dir: PathBuf,
+    pub cache_dir: PathBuf,
+    pub leaderboard_file: PathBuf,
+    pub fixtures_dir: PathBuf,
+    // where the key the encrypted inputs are read with is kept
+    pub key_file: PathBuf,
+    // the key itself, which wins over key_file. Best set from AOC_INPUT_KEY
+    // rather than a file anyone might commit.
+    pub input_key: Option<String>,
+    pub history_file: PathBuf,
+    pub year: u16,
+    pub format: Format,
+    pub iterations: usize,
+    // settings a single day reads itself, e.g. day 4's threshold
+    days: Vec<DaySetting>,
+}
+
+#[derive(Debug, PartialEq)]
+struct DaySetting {
+    year: u16,
+    day: u8,
+    key: String,
+    value: Value,
+}
+
+// What a setting holds, so its environment variable can be read as that.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    String,
+    Integer,
+}
+
+// Each setting's key in the file, the environment variable overriding it
+// and what it holds.
+const KEYS: [(&str, &str, Kind); 11] = [
+    ("paths.inputs", "INPUT_DIR", Kind::String),
+    ("paths.answers", "ANSWERS_DIR", Kind::String),
+    ("paths.cache", "CACHE_DIR", Kind::String),
+    ("paths.leaderboard", "LEADERBOARD", Kind::String),
+    ("paths.fixtures", "FIXTURES_DIR", Kind::String),
+    ("paths.key", "KEY_FILE", Kind::String),
+    ("paths.history", "BENCH_HISTORY", Kind::String),
+    ("cipher.key", "INPUT_KEY", Kind::String),
+    ("run.year", "YEAR", Kind::Integer),
+    ("report.format", "REPORT_FORMAT", Kind::String),
+    ("bench.iterations", "BENCH_ITERATIONS", Kind::Integer),
+];
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            input_dir: PathBuf::from(input::INPUT_DIR),
+            answers_dir: PathBuf::from(input::ANSWERS_DIR),
+            cache_dir: PathBuf::from(CACHE_DIR),
+            leaderboard_file: PathBuf::from(LEADERBOARD_FILE),
+            fixtures_dir: PathBuf::from(fixtures::FIXTURES_DIR),
+            key_file: PathBuf::from(cipher::KEY_FILE),
+            input_key: None,
+            history_file: PathBuf::from(history::HISTORY_PATH),
+            year: cli::DEFAULT_YEAR,
+            format: Format::Markdown,
+            iterations: bench::DEFAULT_ITERATIONS,
+            days: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    // Applies the file's settings over the defaults. `source` names the file
+    // in errors.
+    pub fn parse(text: &str, source: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+
+        let entries =
+            toml::parse(text).map_err(|(line, error)| format!("{}:{}: {}", source, line, error))?;
+        for entry in entries {
+            config
+                .set(&entry.key, entry.value)
+                .map_err(|error| format!("{}:{}: {}", source, entry.line, error))?;
+        }
+
+        Ok(config)
+    }
+
+    // Applies every AOC_* variable in `vars`, ignoring the rest.
+    pub fn apply_env(
+        &mut self,
+        vars: impl Iterator<Item = (String, String)>,
+    ) -> Result<(), String> {
+        for (name, text) in vars {
+            let Some(suffix) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+
+            let (key, value) = match KEYS.iter().find(|(_, variable, _)| *variable == suffix) {
+                Some((key, _, kind)) => (key.to_string(), env_value(&text, *kind)),
+                None => match day_key_from_env(suffix) {
+                    Some(key) => (key, untyped_env_value(&text)),
+                    // AOC_BENCH_MACHINE and friends belong to other parts of the crate
+                    None => continue,
+                },
+            };
+
+            self.set(&key, value)
+                .map_err(|error| format!("{}: {}", name, error))?;
+        }
+
+        Ok(())
+    }
+
+    // The Args a command line is parsed on top of, so flags win over config.
+    pub fn defaults(&self) -> Args {
+        Args {
+            year: self.year,
+            html: self.format == Format::Html,
+            iterations: Some(self.iterations),
+            ..Args::default()
+        }
+    }
+
+    // An integer a day reads for itself, None when it isn't set.
+    pub fn day_integer(&self, year: u16, day: u8, key: &str) -> Option<i64> {
+        self.days
+            .iter()
+            .find(|setting| setting.year == year && setting.day == day && setting.key == key)
+            .and_then(|setting| match setting.value {
+                Value::Integer(value) => Some(value),
+                _ => None,
+            })
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match key {
+            "paths.inputs" => self.input_dir = PathBuf::from(string(key, value)?),
+            "paths.answers" => self.answers_dir = PathBuf::from(string(key, value)?),
+            "paths.cache" => self.cache_dir = PathBuf::from(string(key, value)?),
+            "paths.leaderboard" => self.leaderboard_file = PathBuf::from(string(key, value)?),
+            "paths.fixtures" => self.fixtures_dir = PathBuf::from(string(key, value)?),
+            "paths.key" => self.key_file = PathBuf::from(string(key, value)?),
+            "paths.history" => self.history_file = PathBuf::from(string(key, value)?),
+            "cipher.key" => self.input_key = Some(string(key, value)?),
+            "run.year" => self.year = integer(key, value)?,
+            "report.format" => {
+                self.format = match string(key, value)?.as_str() {
+                    "markdown" => Format::Markdown,
+                    "html" => Format::Html,
+                    other => {
+                        return Err(format!(
+                            "{} must be markdown or html, found '{}'",
+                            key, other
+                        ));
+                    }
+                }
+            }
+            "bench.iterations" => {
+                self.iterations = integer(key, value)?;
+                if self.iterations == 0 {
+                    return Err(format!("{} must be at least 1", key));
+                }
+            }
+            // day 4 compares it against a count of neighbours, so it can't be
+            // negative
+            "2025.day_4.threshold" => {
+                let threshold: u32 = integer(key, value)?;
+                self.set_day(key, Value::Integer(threshold as i64))?;
+            }
+            _ => self.set_day(key, value)?,
+        }
+
+        Ok(())
+    }
+
+    fn set_day(&mut self, key: &str, value: Value) -> Result<(), String> {
+        let (year, day, name) =
+            parse_day_key(key).ok_or_else(|| format!("unknown setting {}", key))?;
+        let setting = DaySetting {
+            year,
+            day,
+            key: name.to_string(),
+            value,
+        };
+        match self
+            .days
+            .iter_mut()
+            .find(|existing| existing.year == year && existing.day == day && existing.key == name)
+        {
+            Some(existing) => *existing = setting,
+            None => self.days.push(setting),
+        }
+
+        Ok(())
+    }
+}
+
+fn string(key: &str, value: Value) -> Result<String, String> {
+    match value {
+        Value::String(value) => Ok(value),
+        other => Err(format!("{} must be a string, found {}", key, other.kind())),
+    }
+}
+
+fn integer<T: TryFrom<i64>>(key: &str, value: Value) -> Result<T, String> {
+    match value {
+        Value::Integer(value) => {
+            T::try_from(value).map_err(|_| format!("{} is out of range: {}", key, value))
+        }
+        other => Err(format!(
+            "{} must be an integer, found {}",
+            key,
+            other.kind()
+        )),
+    }
+}
+
+// 2025.day_4.threshold
+fn parse_day_key(key: &str) -> Option<(u16, u8, &str)> {
+    let mut parts = key.splitn(3, '.');
+    let year = parts.next()?.parse().ok()?;
+    let day = parts.next()?.strip_prefix("day_")?.parse().ok()?;
+    let name = parts.next().filter(|name| !name.contains('.'))?;
+    Some((year, day, name))
+}
+
+// AOC_2025_DAY_4_THRESHOLD is 2025.day_4.threshold
+fn day_key_from_env(suffix: &str) -> Option<String> {
+    let mut parts = suffix.splitn(4, '_');
+    let year = parts.next()?.parse::<u16>().ok()?;
+    if parts.next()? != "DAY" {
+        return None;
+    }
+    let day = parts.next()?.parse::<u8>().ok()?;
+    let name = parts.next()?.to_ascii_lowercase();
+    Some(format!("{}.day_{}.{}", year, day, name))
+}
+
+// Environment variables are untyped, so one is read as whatever its setting
+// holds: AOC_INPUT_DIR=2025 is a directory, not a number. A variable that
+// doesn't parse stays a string for `set` to reject by name.
+fn env_value(text: &str, kind: Kind) -> Value {
+    match (kind, text.parse::<i64>()) {
+        (Kind::Integer, Ok(integer)) => Value::Integer(integer),
+        _ => Value::String(text.to_string()),
+    }
+}
+
+// A day's own settings aren't known here, so read numbers and booleans as
+// such and anything else as a string.
+fn untyped_env_value(text: &str) -> Value {
+    if let Ok(integer) = text.parse::<i64>() {
+        Value::Integer(integer)
+    } else if let Ok(float) = text.parse::<f64>() {
+        Value::Float(float)
+    } else if let Ok(boolean) = text.parse::<bool>() {
+        Value::Boolean(boolean)
+    } else {
+        Value::String(text.to_string())
+    }
+}
+
+// Reads the config file (when there is one) and the environment. A missing
+// aoc.toml just means the defaults, a missing --config file is an error.
+pub fn load(path: Option<&Path>) -> Result<Config, String> {
+    let (path, required) = match path {
+        Some(path) => (path, true),
+        None => (Path::new(CONFIG_PATH), false),
+    };
+
+    let mut config = match fs::read_to_string(path) {
+        Ok(text) => Config::parse(&text, &path.display().to_string())?,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound && !required => {
+            Config::default()
+        }
+        Err(error) => return Err(format!("Could not read {}: {}", path.display(), error)),
+    };
+    config.apply_env(env::vars())?;
+
+    Ok(config)
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// Makes `config` the one `get` returns. Only the first call counts.
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+// The config main loaded, or the defaults when nothing did (e.g. in tests).
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(vars: &[(&str, &str)]) -> impl Iterator<Item = (String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<(String, String)>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+[paths]
+inputs = "puzzles"
+
+[report]
+format = "html"
+
+[2025.day_4]
+threshold = 5
+"#,
+            "aoc.toml",
+        )
+        .unwrap();
+
+        assert_eq!(config.input_dir, PathBuf::from("puzzles"));
+        assert_eq!(config.answers_dir, PathBuf::from("answers"));
+        assert_eq!(config.format, Format::Html);
+        assert_eq!(config.day_integer(2025, 4, "threshold"), Some(5));
+        assert_eq!(config.day_integer(2025, 3, "threshold"), None);
+    }
+
+    #[test]
+    fn test_checked_in_config_matches_defaults() {
+        let config = Config::parse(include_str!("../aoc.toml"), "aoc.toml").unwrap();
+        let defaults = Config::default();
+
+        assert_eq!(config.input_dir, defaults.input_dir);
+        assert_eq!(config.answers_dir, defaults.answers_dir);
+        assert_eq!(config.cache_dir, defaults.cache_dir);
+        assert_eq!(config.leaderboard_file, defaults.leaderboard_file);
+        assert_eq!(config.fixtures_dir, defaults.fixtures_dir);
+        assert_eq!(config.key_file, defaults.key_file);
+        assert_eq!(config.input_key, defaults.input_key);
+        assert_eq!(config.history_file, defaults.history_file);
+        assert_eq!(config.year, defaults.year);
+        assert_eq!(config.format, defaults.format);
+        assert_eq!(config.iterations, defaults.iterations);
+        assert_eq!(config.day_integer(2025, 4, "threshold"), Some(4));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Config::parse("[paths]\ninptus = \"x\"", "aoc.toml"),
+            Err(String::from("aoc.toml:2: unknown setting paths.inptus"))
+        );
+        assert_eq!(
+            Config::parse("[run]\nyear = \"2025\"", "aoc.toml"),
+            Err(String::from(
+                "aoc.toml:2: run.year must be an integer, found a string"
+            ))
+        );
+        assert_eq!(
+            Config::parse("[bench]\niterations = 0", "aoc.toml"),
+            Err(String::from(
+                "aoc.toml:2: bench.iterations must be at least 1"
+            ))
+        );
+        assert!(Config::parse("[report]\nformat = \"pdf\"", "aoc.toml").is_err());
+        assert_eq!(
+            Config::parse("[2025.day_4]\nthreshold = -1", "aoc.toml"),
+            Err(String::from(
+                "aoc.toml:2: 2025.day_4.threshold is out of range: -1"
+            ))
+        );
+        assert_eq!(
+            Config::parse("[2025.day_4]\nthreshold = \"four\"", "aoc.toml"),
+            Err(String::from(
+                "aoc.toml:2: 2025.day_4.threshold must be an integer, found a string"
+            ))
+        );
+        assert!(Config::parse("[paths]\nsession = \".session\"", "aoc.toml").is_err());
+        assert!(Config::parse("[paths", "aoc.toml").is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let mut config = Config::parse(
+            "[run]\nyear = 2024\n[2025.day_4]\nthreshold = 5",
+            "aoc.toml",
+        )
+        .unwrap();
+        config
+            .apply_env(vars(&[
+                ("AOC_YEAR", "2025"),
+                ("AOC_2025_DAY_4_THRESHOLD", "3"),
+                ("AOC_KEY", "not ours"),
+                ("HOME", "/root"),
+            ]))
+            .unwrap();
+
+        assert_eq!(config.year, 2025);
+        assert_eq!(config.day_integer(2025, 4, "threshold"), Some(3));
+
+        // each variable is read as what its setting holds
+        config
+            .apply_env(vars(&[
+                ("AOC_INPUT_DIR", "2025"),
+                ("AOC_INPUT_KEY", "0123"),
+                ("AOC_BENCH_HISTORY", "/tmp/history.txt"),
+            ]))
+            .unwrap();
+        assert_eq!(config.input_dir, PathBuf::from("2025"));
+        assert_eq!(config.input_key, Some(String::from("0123")));
+        assert_eq!(config.history_file, PathBuf::from("/tmp/history.txt"));
+
+        assert_eq!(
+            config.apply_env(vars(&[("AOC_YEAR", "soon")])),
+            Err(String::from(
+                "AOC_YEAR: run.year must be an integer, found a string"
+            ))
+        );
+        assert_eq!(
+            config.apply_env(vars(&[("AOC_2025_DAY_4_THRESHOLD", "-2")])),
+            Err(String::from(
+                "AOC_2025_DAY_4_THRESHOLD: 2025.day_4.threshold is out of range: -2"
+            ))
+        );
+        assert_eq!(
+            config.apply_env(vars(&[("AOC_2025_DAY_4_THRESHOLD", "4.5")])),
+            Err(String::from(
+                "AOC_2025_DAY_4_THRESHOLD: 2025.day_4.threshold must be an integer, found a float"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_flags_override_config() {
+        let config =
+            Config::parse("[run]\nyear = 2024\n[bench]\niterations = 9", "aoc.toml").unwrap();
+
+        let args = cli::parse_args_with(
+            "--year 2025".split(' ').map(String::from),
+            config.defaults(),
+        )
+        .unwrap();
+        assert_eq!(args.year, 2025);
+        assert_eq!(args.iterations, Some(9));
+    }
+}
@@ -0,0 +1,475 @@
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use crate::cli::Args;
+use crate::registry;
+use crate::runner;
+use crate::util::narrate;
+
+const HELP: &str = "Commands:
+  s, step            run to the next step (or just press enter)
+  c, continue        run until a breakpoint is hit
+  p, print           show the state at this step
+  b, break COND      stop at steps where COND holds, e.g. b zero_clicks > 2
+  d, delete N        remove breakpoint N
+  i, info            list the breakpoints
+  q, quit            stop debugging and finish the run
+  h, help            show this list";
+
+// One step of a day's simulation (a rotation, a round), with the values a
+// breakpoint can test and anything else worth printing when paused.
+#[derive(Debug, PartialEq)]
+pub struct Step {
+    pub label: String,
+    pub values: Vec<(&'static str, i64)>,
+    pub state: Option<String>,
+}
+
+impl Step {
+    pub fn new(label: String) -> Self {
+        Step {
+            label,
+            values: Vec::new(),
+            state: None,
+        }
+    }
+
+    pub fn value(mut self, name: &'static str, value: i64) -> Self {
+        self.values.push((name, value));
+        self
+    }
+
+    pub fn state(mut self, state: String) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    fn get(&self, name: &str) -> Option<i64> {
+        self.values
+            .iter()
+            .find(|(value_name, _)| *value_name == name)
+            .map(|&(_, value)| value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl Comparison {
+    // longest first, so <= isn't read as <
+    const ALL: [(&'static str, Comparison); 6] = [
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("==", Comparison::Equal),
+        ("!=", Comparison::NotEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+    ];
+
+    fn symbol(self) -> &'static str {
+        Comparison::ALL
+            .iter()
+            .find(|(_, comparison)| *comparison == self)
+            .map(|(symbol, _)| *symbol)
+            .unwrap_or("?")
+    }
+
+    fn holds(self, left: i64, right: i64) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Greater => left > right,
+        }
+    }
+}
+
+// A condition like `removed < 5`, comparing one of a step's values with a
+// number.
+#[derive(Debug, PartialEq)]
+pub struct Breakpoint {
+    name: String,
+    comparison: Comparison,
+    value: i64,
+}
+
+impl Breakpoint {
+    pub fn parse(condition: &str) -> Result<Breakpoint, String> {
+        let invalid = || {
+            format!(
+                "Invalid breakpoint '{}', expected a condition like zero_clicks > 2",
+                condition
+            )
+        };
+
+        let (index, symbol, comparison) = Comparison::ALL
+            .iter()
+            .filter_map(|&(symbol, comparison)| {
+                condition
+                    .find(symbol)
+                    .map(|index| (index, symbol, comparison))
+            })
+            .min_by_key(|&(index, symbol, _)| (index, usize::MAX - symbol.len()))
+            .ok_or_else(invalid)?;
+
+        let name = condition[..index].trim();
+        let value = condition[index + symbol.len()..].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(invalid());
+        }
+
+        Ok(Breakpoint {
+            name: name.to_string(),
+            comparison,
+            value: value.parse().map_err(|_| invalid())?,
+        })
+    }
+
+    fn matches(&self, step: &Step) -> bool {
+        step.get(&self.name)
+            .is_some_and(|value| self.comparison.holds(value, self.value))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.name,
+            self.comparison.symbol(),
+            self.value
+        )
+    }
+}
+
+// Pauses a day's simulation at breakpoints (or every step when stepping) and
+// reads commands until told to move on. Running out of commands finishes the
+// run without pausing again.
+pub struct Debugger<'a> {
+    breakpoints: Vec<Breakpoint>,
+    commands: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+    stepping: bool,
+    quit: bool,
+    steps: usize,
+    // the first write or read that failed, steps are reported through a
+    // callback that can't return it
+    error: Option<io::Error>,
+}
+
+impl<'a> Debugger<'a> {
+    // Starts paused on the first step unless there are breakpoints to run to.
+    pub fn new(
+        breakpoints: Vec<Breakpoint>,
+        commands: &'a mut dyn BufRead,
+        output: &'a mut dyn Write,
+    ) -> Self {
+        Debugger {
+            stepping: breakpoints.is_empty(),
+            breakpoints,
+            commands,
+            output,
+            quit: false,
+            steps: 0,
+            error: None,
+        }
+    }
+
+    pub fn on_step(&mut self, step: Step) {
+        self.steps += 1;
+        if self.quit {
+            return;
+        }
+
+        if self.steps == 1 {
+            self.warn_unknown_names(&step);
+        }
+
+        let hit = self
+            .breakpoints
+            .iter()
+            .position(|breakpoint| breakpoint.matches(&step));
+        if !self.stepping && hit.is_none() {
+            return;
+        }
+
+        if let Err(error) = self.pause(&step, hit) {
+            self.error = Some(error);
+            self.quit = true;
+        }
+    }
+
+    // How many steps ran, including any after quitting, and the first I/O
+    // error if there was one.
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.steps),
+        }
+    }
+
+    fn pause(&mut self, step: &Step, hit: Option<usize>) -> io::Result<()> {
+        if let Some(index) = hit {
+            writeln!(
+                self.output,
+                "Breakpoint {} hit: {}",
+                index + 1,
+                self.breakpoints[index]
+            )?;
+        }
+        writeln!(self.output, "[step {}] {}", self.steps, step.label)?;
+
+        loop {
+            write!(self.output, "(debug) ")?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.commands.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                self.quit = true;
+                return Ok(());
+            }
+
+            let line = line.trim();
+            let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+            match command {
+                "" | "s" | "step" => {
+                    self.stepping = true;
+                    return Ok(());
+                }
+                "c" | "continue" => {
+                    self.stepping = false;
+                    return Ok(());
+                }
+                "q" | "quit" => {
+                    self.quit = true;
+                    return Ok(());
+                }
+                "p" | "print" => {
+                    for (name, value) in &step.values {
+                        writeln!(self.output, "  {} = {}", name, value)?;
+                    }
+                    if let Some(state) = &step.state {
+                        writeln!(self.output, "{}", state)?;
+                    }
+                }
+                "b" | "break" => match Breakpoint::parse(rest) {
+                    Ok(breakpoint) => {
+                        if step.get(&breakpoint.name).is_none() {
+                            writeln!(
+                                self.output,
+                                "No value named {} at this step, have: {}",
+                                breakpoint.name,
+                                names(step)
+                            )?;
+                        } else {
+                            self.breakpoints.push(breakpoint);
+                            writeln!(
+                                self.output,
+                                "Breakpoint {}: {}",
+                                self.breakpoints.len(),
+                                self.breakpoints[self.breakpoints.len() - 1]
+                            )?;
+                        }
+                    }
+                    Err(error) => writeln!(self.output, "{}", error)?,
+                },
+                "d" | "delete" => match rest.trim().parse::<usize>() {
+                    Ok(number) if (1..=self.breakpoints.len()).contains(&number) => {
+                        let breakpoint = self.breakpoints.remove(number - 1);
+                        writeln!(self.output, "Deleted breakpoint {}: {}", number, breakpoint)?;
+                    }
+                    _ => writeln!(self.output, "No breakpoint {}", rest.trim())?,
+                },
+                "i" | "info" => {
+                    if self.breakpoints.is_empty() {
+                        writeln!(self.output, "No breakpoints")?;
+                    }
+                    for (index, breakpoint) in self.breakpoints.iter().enumerate() {
+                        writeln!(self.output, "  {}: {}", index + 1, breakpoint)?;
+                    }
+                }
+                "h" | "help" => writeln!(self.output, "{}", HELP)?,
+                _ => writeln!(self.output, "Unknown command: {}, try help", command)?,
+            }
+        }
+    }
+
+    // A breakpoint on a value the day never reports would silently never hit.
+    fn warn_unknown_names(&mut self, step: &Step) {
+        for breakpoint in &self.breakpoints {
+            if step.get(&breakpoint.name).is_none() {
+                let _ = writeln!(
+                    self.output,
+                    "Warning: no value named {}, have: {}",
+                    breakpoint.name,
+                    names(step)
+                );
+            }
+        }
+    }
+}
+
+fn names(step: &Step) -> String {
+    step.values
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args
+        .day
+        .ok_or_else(|| String::from("debug needs a --day to step through"))?;
+    let solution = registry::find(args.year, day)
+        .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?;
+    let trace = solution
+        .debug
+        .ok_or_else(|| format!("{} day {} has no debugger", solution.year, solution.day))?;
+
+    let example = runner::find_example(&solution, args)?;
+    let lines = runner::read_lines(&solution, args, example)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let stdin = io::stdin();
+    let mut commands = stdin.lock();
+    let mut output = io::stdout();
+    let debug_error = |error: io::Error| format!("Could not debug: {}", error);
+
+    println!("Type help for the list of commands.");
+    for part in parts {
+        println!(
+            "--- {} Day {}: {}, part {} ---",
+            solution.year, solution.day, solution.title, part
+        );
+
+        let breakpoints = args
+            .breakpoints
+            .iter()
+            .map(|condition| Breakpoint::parse(condition))
+            .collect::<Result<Vec<Breakpoint>, String>>()?;
+        let mut debugger = Debugger::new(breakpoints, &mut commands, &mut output);
+        let answer = trace(&lines, part, &mut |step| debugger.on_step(step));
+        let steps = debugger.finish().map_err(debug_error)?;
+
+        println!(
+            "Part {}: {} ({})",
+            part,
+            answer,
+            narrate::count(steps, "step", "steps")
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts down from `start`, one step per number
+    fn countdown(start: i64, on_step: &mut dyn FnMut(Step)) {
+        for number in (0..=start).rev() {
+            on_step(
+                Step::new(format!("at {}", number))
+                    .value("number", number)
+                    .state(format!("{} to go", number)),
+            );
+        }
+    }
+
+    fn session(breakpoints: &[&str], commands: &str) -> (String, usize) {
+        let breakpoints = breakpoints
+            .iter()
+            .map(|condition| Breakpoint::parse(condition).unwrap())
+            .collect();
+        let mut commands = commands.as_bytes();
+        let mut output = Vec::new();
+
+        let mut debugger = Debugger::new(breakpoints, &mut commands, &mut output);
+        countdown(5, &mut |step| debugger.on_step(step));
+        let steps = debugger.finish().unwrap();
+
+        (String::from_utf8(output).unwrap(), steps)
+    }
+
+    #[test]
+    fn test_parse_breakpoint() {
+        assert_eq!(
+            Breakpoint::parse("zero_clicks > 2").map(|breakpoint| breakpoint.to_string()),
+            Ok(String::from("zero_clicks > 2"))
+        );
+        assert_eq!(
+            Breakpoint::parse("removed<=5").map(|breakpoint| breakpoint.to_string()),
+            Ok(String::from("removed <= 5"))
+        );
+        assert_eq!(
+            Breakpoint::parse("line == -1").map(|breakpoint| breakpoint.to_string()),
+            Ok(String::from("line == -1"))
+        );
+        assert!(Breakpoint::parse("removed").is_err());
+        assert!(Breakpoint::parse("< 5").is_err());
+        assert!(Breakpoint::parse("removed < five").is_err());
+        assert!(Breakpoint::parse("a b < 5").is_err());
+    }
+
+    #[test]
+    fn test_step_and_print() {
+        let (output, steps) = session(&[], "s\n\np\nq\n");
+        assert_eq!(steps, 6);
+        assert_eq!(
+            output,
+            "[step 1] at 5
+(debug) [step 2] at 4
+(debug) [step 3] at 3
+(debug)   number = 3
+3 to go
+(debug) "
+        );
+    }
+
+    #[test]
+    fn test_continue_to_breakpoint() {
+        let (output, steps) = session(&["number < 2"], "c\nd 1\nc\n");
+        assert_eq!(steps, 6);
+        assert_eq!(
+            output,
+            "Breakpoint 1 hit: number < 2
+[step 5] at 1
+(debug) Breakpoint 1 hit: number < 2
+[step 6] at 0
+(debug) Deleted breakpoint 1: number < 2
+(debug) "
+        );
+    }
+
+    #[test]
+    fn test_break_while_paused() {
+        let (output, _) = session(&[], "b count > 1\nb number == 2\ni\nc\n");
+        assert!(output.contains("No value named count at this step, have: number"));
+        assert!(output.contains("Breakpoint 1: number == 2\n"));
+        assert!(output.contains("Breakpoint 1 hit: number == 2\n[step 4] at 2\n"));
+    }
+
+    #[test]
+    fn test_running_out_of_commands_finishes() {
+        let (output, steps) = session(&["nope > 0"], "");
+        assert_eq!(steps, 6);
+        assert_eq!(output, "Warning: no value named nope, have: number\n");
+    }
+}
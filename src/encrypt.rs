@@ -0,0 +1,135 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::Args;
+use crate::config;
+use crate::util::cipher;
+
+// Encrypting only keeps plain inputs out of commits from here on: any commit
+// that already has them still hands them out to whoever clones it. This
+// repo's first commits do, under src/ and then inputs/. Getting them out of
+// the history means rewriting it and force pushing, which is left to whoever
+// owns the repo.
+const HISTORY_NOTE: &str = "Plain inputs committed before this are still in the git history, \
+to purge them run:
+  git filter-repo --invert-paths --path-glob 'inputs/*/*.txt' --path-glob 'src/day_*_input.txt'
+and force push, everyone else then has to clone again";
+
+// inputs/<year>/*<extension>, sorted so the output is stable
+fn input_files(year: u16, extension: &str) -> Result<Vec<PathBuf>, String> {
+    let directory = config::get().input_dir.join(year.to_string());
+    let entries = fs::read_dir(&directory)
+        .map_err(|error| format!("Could not read {}: {}", directory.display(), error))?;
+
+    let mut files = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(extension))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    Ok(files)
+}
+
+fn load_or_generate_key() -> Result<cipher::Key, String> {
+    let config = config::get();
+    if config.input_key.is_some() || config.key_file.exists() {
+        return cipher::load_key();
+    }
+
+    let path = &config.key_file;
+    let key = cipher::generate_key()?;
+    fs::write(path, format!("{}\n", cipher::format_key(&key)))
+        .map_err(|error| format!("Could not write {}: {}", path.display(), error))?;
+    println!(
+        "Generated a new key in {}, keep it somewhere safe and out of git",
+        path.display()
+    );
+    Ok(key)
+}
+
+pub fn encrypt_inputs(args: &Args) -> Result<(), String> {
+    let files = input_files(args.year, ".txt")?;
+    if files.is_empty() {
+        println!("No plain inputs to encrypt for {}", args.year);
+        return Ok(());
+    }
+
+    let key = load_or_generate_key()?;
+
+    for path in files {
+        let plaintext = fs::read(&path)
+            .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+
+        let mut encrypted_path = path.clone().into_os_string();
+        encrypted_path.push(".enc");
+        let encrypted_path = PathBuf::from(encrypted_path);
+
+        fs::write(&encrypted_path, cipher::encrypt(&key, &plaintext)?)
+            .map_err(|error| format!("Could not write {}: {}", encrypted_path.display(), error))?;
+        fs::remove_file(&path)
+            .map_err(|error| format!("Could not remove {}: {}", path.display(), error))?;
+
+        println!("{} -> {}", path.display(), encrypted_path.display());
+    }
+    println!("{}", HISTORY_NOTE);
+
+    Ok(())
+}
+
+pub fn decrypt_inputs(args: &Args) -> Result<(), String> {
+    let files = input_files(args.year, ".txt.enc")?;
+    if files.is_empty() {
+        println!("No encrypted inputs to decrypt for {}", args.year);
+        return Ok(());
+    }
+
+    let key = cipher::load_key()?;
+
+    for path in files {
+        let encrypted = fs::read(&path)
+            .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+        let plaintext = cipher::decrypt(&key, &encrypted)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+        let plain_path = path.with_extension("");
+        fs::write(&plain_path, plaintext)
+            .map_err(|error| format!("Could not write {}: {}", plain_path.display(), error))?;
+        fs::remove_file(&path)
+            .map_err(|error| format!("Could not remove {}: {}", path.display(), error))?;
+
+        println!("{} -> {}", path.display(), plain_path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+
+    // .gitignore keeps new plain inputs out, but not ones git already tracks
+    #[test]
+    fn test_no_plain_inputs_are_tracked() {
+        let Ok(output) = Command::new("git")
+            .args(["ls-files", "inputs"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+        else {
+            return;
+        };
+        // outside a checkout there's nothing tracked to check
+        if !output.status.success() {
+            return;
+        }
+
+        let tracked = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|path| path.ends_with(".txt"))
+            .map(String::from)
+            .collect::<Vec<String>>();
+        assert!(
+            tracked.is_empty(),
+            "plain inputs are tracked, run encrypt-inputs and git rm --cached them: {:?}",
+            tracked
+        );
+    }
+}
@@ -0,0 +1,384 @@
+// C-compatible entry points for the reusable parts of the solutions, built
+// into the cdylib. include/advent_of_code_2025.h is generated from this file
+// by the test at the bottom; rerun the tests with UPDATE_SNAPSHOTS=1 after
+// changing a signature.
+//
+// No function panics across the FFI boundary, which would abort the host.
+// Arguments outside what a function handles (null or malformed data, a dial
+// position off the dial, sizes that overflow) give its error value instead:
+// a negative number, a turn to position -1, or SIZE_MAX for a count. Anything
+// that still panics inside is caught and gives the same error value.
+#![allow(clippy::missing_safety_doc)]
+
+use std::panic::{self, UnwindSafe};
+use std::slice;
+
+// What the dial functions return for arguments they don't handle.
+const INVALID_TURN: AocDialTurn = AocDialTurn {
+    position: -1,
+    zero_clicks: -1,
+};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AocDialTurn {
+    pub position: i32,
+    pub zero_clicks: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AocRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+// What aoc_merge_ranges returns for arguments it doesn't handle, since 0 is
+// a real answer for no ranges.
+const MERGE_FAILED: usize = usize::MAX;
+
+// Whether `length` values of T fit in one slice, which from_raw_parts needs
+// to span no more than isize::MAX bytes.
+fn fits_in_slice<T>(length: usize) -> bool {
+    length
+        .checked_mul(size_of::<T>())
+        .is_some_and(|bytes| bytes <= isize::MAX as usize)
+}
+
+// Runs `f`, giving `error` if it panics.
+fn guard<T>(error: T, f: impl FnOnce() -> T + UnwindSafe) -> T {
+    panic::catch_unwind(f).unwrap_or(error)
+}
+
+// Turns from `position` (0 to 99) by a `distance` of 0 or more. Gives
+// position -1 for anything else.
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_turn_left_with_clicks(position: i32, distance: i32) -> AocDialTurn {
+    if !(0..=99).contains(&position) || distance < 0 {
+        return INVALID_TURN;
+    }
+
+    guard(INVALID_TURN, || {
+        let (position, zero_clicks) = aoc_core::dial::turn_left_with_clicks(position, distance);
+        AocDialTurn {
+            position,
+            zero_clicks,
+        }
+    })
+}
+
+// Turns from `position` (0 to 99) by a `distance` of 0 or more. Gives
+// position -1 for anything else.
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_turn_right_with_clicks(position: i32, distance: i32) -> AocDialTurn {
+    if !(0..=99).contains(&position) || distance < 0 {
+        return INVALID_TURN;
+    }
+
+    guard(INVALID_TURN, || {
+        let (position, zero_clicks) = aoc_core::dial::turn_right_with_clicks(position, distance);
+        AocDialTurn {
+            position,
+            zero_clicks,
+        }
+    })
+}
+
+// true when the ID is some sequence of digits repeated exactly twice
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_is_doubled_id(id: i64) -> bool {
+    guard(false, || !aoc_core::ids::is_valid_id_arithmetic(id))
+}
+
+// true when the ID is some sequence of digits repeated at least twice
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_is_repeated_id(id: i64) -> bool {
+    guard(false, || {
+        !aoc_core::ids::is_valid_id_part_two_arithmetic(id)
+    })
+}
+
+// The largest joltage from turning on `batteries` of the `length` digits in `bank`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_max_joltage(bank: *const u8, length: usize, batteries: usize) -> i64 {
+    if bank.is_null() || !fits_in_slice::<u8>(length) || batteries == 0 || batteries > 18 {
+        return -1;
+    }
+
+    let bank = unsafe { slice::from_raw_parts(bank, length) };
+    if bank.is_empty() || !bank.iter().all(u8::is_ascii_digit) {
+        return -1;
+    }
+
+    guard(-1, || {
+        aoc_core::joltage::get_max_joltage_from_digits(bank, batteries)
+    })
+}
+
+// Counts the rolls (@) with fewer than four rolls around them in a grid of
+// `width` * `height` cells stored row by row without line breaks.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_count_accessible_rolls(
+    cells: *const u8,
+    width: usize,
+    height: usize,
+) -> i64 {
+    let Some(length) = width
+        .checked_mul(height)
+        .filter(|&length| fits_in_slice::<u8>(length))
+    else {
+        return -1;
+    };
+    if cells.is_null() || length == 0 {
+        return -1;
+    }
+
+    let cells = unsafe { slice::from_raw_parts(cells, length) };
+    guard(-1, || {
+        let grid = aoc_core::grid::Grid::from_bytes(width, height, cells);
+
+        let mut count = 0;
+        for row in 0..height {
+            for column in 0..width {
+                if grid.get(row, column) == b'@' && grid.count_adjacent(row, column, b'@') < 4 {
+                    count += 1;
+                }
+            }
+        }
+        count
+    })
+}
+
+// Merges overlapping ranges in place, sorted by start, and returns how many
+// remain. SIZE_MAX, leaving the ranges as they were, when `ranges` is null or
+// a range ends before it starts.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_merge_ranges(ranges: *mut AocRange, length: usize) -> usize {
+    if ranges.is_null() || !fits_in_slice::<AocRange>(length) {
+        return MERGE_FAILED;
+    }
+
+    let ranges = unsafe { slice::from_raw_parts_mut(ranges, length) };
+    let pairs = ranges
+        .iter()
+        .map(|range| (range.start, range.end))
+        .collect::<Vec<(i64, i64)>>();
+    if pairs.iter().any(|(start, end)| start > end) {
+        return MERGE_FAILED;
+    }
+    let Some(merged) = guard(None, || Some(aoc_core::ranges::merge_ranges(&pairs))) else {
+        return MERGE_FAILED;
+    };
+
+    for (range, (start, end)) in ranges.iter_mut().zip(&merged) {
+        *range = AocRange {
+            start: *start,
+            end: *end,
+        };
+    }
+    merged.len()
+}
+
+// How many values the ranges cover, counting overlaps once. -1 when a range
+// ends before it starts or the count doesn't fit an int64_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_count_fresh_ids(ranges: *const AocRange, length: usize) -> i64 {
+    if ranges.is_null() || !fits_in_slice::<AocRange>(length) {
+        return -1;
+    }
+
+    let pairs = unsafe { slice::from_raw_parts(ranges, length) }
+        .iter()
+        .map(|range| (range.start, range.end))
+        .collect::<Vec<(i64, i64)>>();
+    if pairs.iter().any(|(start, end)| start > end) {
+        return -1;
+    }
+
+    guard(-1, || {
+        // count_covered without the overflow, e.g. 0 to INT64_MAX
+        aoc_core::ranges::merge_ranges(&pairs)
+            .iter()
+            .try_fold(0_i64, |total, (start, end)| {
+                end.checked_sub(*start)?
+                    .checked_add(1)
+                    .and_then(|covered| total.checked_add(covered))
+            })
+            .unwrap_or(-1)
+    })
+}
+
+// Builds the C header from the declarations above: #[repr(C)] structs become
+// typedefs and every extern "C" function becomes a prototype, keeping the
+// comment directly above it.
+pub fn generate_header(source: &str) -> String {
+    let mut header = String::from(
+        "/* Generated from src/ffi.rs, do not edit by hand. */\n\
+         #ifndef ADVENT_OF_CODE_2025_H\n\
+         #define ADVENT_OF_CODE_2025_H\n\n\
+         #include <stdbool.h>\n\
+         #include <stddef.h>\n\
+         #include <stdint.h>\n\n\
+         #ifdef __cplusplus\n\
+         extern \"C\" {\n\
+         #endif\n",
+    );
+
+    let lines = source.lines().collect::<Vec<&str>>();
+    let mut comments: Vec<&str> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index].trim();
+        index += 1;
+
+        if let Some(comment) = line.strip_prefix("// ") {
+            comments.push(comment);
+            continue;
+        }
+        if line.starts_with("#[") {
+            continue;
+        }
+
+        if let Some(name) = line
+            .strip_prefix("pub struct ")
+            .and_then(|rest| rest.strip_suffix(" {"))
+        {
+            header.push_str(&format!("\ntypedef struct {} {{\n", name));
+            while index < lines.len() && lines[index].trim() != "}" {
+                let (field, field_type) = lines[index]
+                    .trim()
+                    .trim_start_matches("pub ")
+                    .trim_end_matches(',')
+                    .split_once(": ")
+                    .unwrap();
+                header.push_str(&format!("    {} {};\n", c_type(field_type), field));
+                index += 1;
+            }
+            header.push_str(&format!("}} {};\n", name));
+        } else if line.starts_with("pub extern \"C\" fn ")
+            || line.starts_with("pub unsafe extern \"C\" fn ")
+        {
+            // signatures may be wrapped over several lines by rustfmt
+            let mut signature = line.to_string();
+            while !signature.ends_with('{') {
+                signature.push_str(lines[index].trim());
+                index += 1;
+            }
+            header.push('\n');
+            for comment in &comments {
+                header.push_str(&format!("/* {} */\n", comment));
+            }
+            header.push_str(&c_prototype(&signature));
+        }
+
+        comments.clear();
+    }
+
+    header.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif\n");
+    header
+}
+
+fn c_type(rust_type: &str) -> String {
+    match rust_type.trim() {
+        "bool" => String::from("bool"),
+        "i32" => String::from("int32_t"),
+        "i64" => String::from("int64_t"),
+        "u8" => String::from("uint8_t"),
+        "usize" => String::from("size_t"),
+        pointer if pointer.starts_with("*const ") => format!("const {}*", c_type(&pointer[7..])),
+        pointer if pointer.starts_with("*mut ") => format!("{}*", c_type(&pointer[5..])),
+        other => other.to_string(),
+    }
+}
+
+fn c_prototype(signature: &str) -> String {
+    let (name_and_params, rest) = signature.split_once(')').unwrap();
+    let (name, params) = name_and_params.split_once('(').unwrap();
+    let name = name.rsplit(' ').next().unwrap();
+    let return_type = rest
+        .trim_end_matches('{')
+        .trim()
+        .strip_prefix("-> ")
+        .map(c_type)
+        .unwrap_or_else(|| String::from("void"));
+
+    let params = params
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (param_name, param_type) = param.split_once(": ").unwrap();
+            format!("{} {}", c_type(param_type), param_name)
+        })
+        .collect::<Vec<String>>();
+
+    format!("{} {}({});\n", return_type, name, params.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::snapshot::assert_matches_file;
+
+    #[test]
+    fn test_c_header_is_up_to_date() {
+        let source = include_str!("ffi.rs");
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("include")
+            .join("advent_of_code_2025.h");
+        assert_matches_file(&path, &generate_header(source));
+    }
+
+    #[test]
+    fn test_c_prototype() {
+        assert_eq!(
+            c_prototype(
+                "pub unsafe extern \"C\" fn aoc_merge_ranges(ranges: *mut AocRange, length: usize) -> usize {"
+            ),
+            "size_t aoc_merge_ranges(AocRange* ranges, size_t length);\n"
+        );
+        assert_eq!(
+            c_prototype("pub extern \"C\" fn aoc_is_repeated_id(id: i64) -> bool {"),
+            "bool aoc_is_repeated_id(int64_t id);\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_and_count_ranges() {
+        let mut ranges = [
+            AocRange { start: 3, end: 5 },
+            AocRange { start: 10, end: 14 },
+            AocRange { start: 16, end: 20 },
+            AocRange { start: 12, end: 18 },
+        ];
+        assert_eq!(
+            unsafe { aoc_count_fresh_ids(ranges.as_ptr(), ranges.len()) },
+            14
+        );
+        assert_eq!(
+            unsafe { aoc_merge_ranges(ranges.as_mut_ptr(), ranges.len()) },
+            2
+        );
+        assert_eq!(ranges[1], AocRange { start: 10, end: 20 });
+        assert_eq!(unsafe { aoc_count_fresh_ids(std::ptr::null(), 0) }, -1);
+
+        assert_eq!(
+            unsafe { aoc_merge_ranges(std::ptr::null_mut(), 0) },
+            MERGE_FAILED
+        );
+        let mut backwards = [AocRange { start: 5, end: 3 }];
+        assert_eq!(
+            unsafe { aoc_merge_ranges(backwards.as_mut_ptr(), 1) },
+            MERGE_FAILED
+        );
+        assert_eq!(unsafe { aoc_merge_ranges(ranges.as_mut_ptr(), 0) }, 0);
+    }
+
+    #[test]
+    fn test_fits_in_slice() {
+        assert!(fits_in_slice::<u8>(isize::MAX as usize));
+        assert!(!fits_in_slice::<u8>(isize::MAX as usize + 1));
+        assert!(!fits_in_slice::<AocRange>(usize::MAX / 4));
+    }
+}
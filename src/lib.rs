@@ -0,0 +1,19 @@
+pub mod anonymize;
+pub mod bench;
+pub mod cli;
+pub mod complexity;
+pub mod config;
+pub mod debug;
+pub mod encrypt;
+pub mod ffi;
+pub mod lint;
+pub mod model;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod serve;
+pub mod solution;
+pub mod stats;
+pub mod tui;
+pub mod util;
+pub mod year_2025;
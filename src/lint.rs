@@ -0,0 +1,402 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::Args;
+use crate::registry;
+use crate::solution::Solution;
+use crate::util::input;
+
+// One thing wrong with an input, `line` and `column` counting from 1. Columns
+// count chars rather than bytes, the same as the caret under the snippet.
+#[derive(Debug, PartialEq)]
+pub struct Problem {
+    pub line: usize,
+    // where on the line the problem starts, when it points at one spot
+    pub column: Option<usize>,
+    pub message: String,
+    // what the line should have looked like, e.g. "a range like 3-5"
+    pub expected: Option<&'static str>,
+    pub hint: Option<&'static str>,
+}
+
+impl Problem {
+    pub fn new(line: usize, message: String) -> Self {
+        Problem {
+            line,
+            column: None,
+            message,
+            expected: None,
+            hint: None,
+        }
+    }
+
+    // A field that failed to parse, `column` being where the field starts.
+    pub fn field(line: usize, column: usize, error: FieldError) -> Self {
+        Problem {
+            column: Some(column + error.offset),
+            hint: error.hint,
+            ..Problem::new(line, error.message)
+        }
+    }
+
+    pub fn at(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn expected(mut self, expected: &'static str) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    pub fn hint(mut self, hint: &'static str) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    // Renders the problem with the offending line and a caret under the bad
+    // column, the way rustc shows its errors:
+    //
+    // error: expected L or R, found 'X'
+    //  --> inputs/2025/day_1.txt:2:1
+    //   |
+    // 2 | X30
+    //   | ^
+    //   = expected: a rotation like L68
+    //   = hint: direction must be L or R
+    pub fn render(&self, source: &str, lines: &[String]) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let mut rendered = format!("error: {}\n", self.message);
+
+        match self.column {
+            Some(column) => {
+                rendered += &format!("{}--> {}:{}:{}\n", gutter, source, self.line, column)
+            }
+            None => rendered += &format!("{}--> {}:{}\n", gutter, source, self.line),
+        }
+
+        if let Some(line) = lines.get(self.line - 1) {
+            let (snippet, caret) = snippet(line, self.column);
+            rendered += &format!("{} |\n", gutter);
+            rendered += &format!("{} | {}\n", self.line, snippet);
+            if let Some(caret) = caret {
+                rendered += &format!("{} | {}^\n", gutter, " ".repeat(caret));
+            }
+        }
+
+        if let Some(expected) = self.expected {
+            rendered += &format!("{} = expected: {}\n", gutter, expected);
+        }
+        if let Some(hint) = self.hint {
+            rendered += &format!("{} = hint: {}\n", gutter, hint);
+        }
+
+        rendered
+    }
+}
+
+// Lines longer than this are cut down to the part around the column.
+const SNIPPET_WIDTH: usize = 80;
+
+// The part of `line` to show and how far in the caret goes, if there is one.
+fn snippet(line: &str, column: Option<usize>) -> (String, Option<usize>) {
+    let chars = line.chars().collect::<Vec<char>>();
+    let caret = column.map(|column| column - 1);
+
+    if chars.len() <= SNIPPET_WIDTH {
+        return (line.to_string(), caret);
+    }
+
+    let center = caret.unwrap_or(0).min(chars.len());
+    let start = center.saturating_sub(SNIPPET_WIDTH / 2);
+    let end = (start + SNIPPET_WIDTH).min(chars.len());
+
+    let mut snippet = chars[start..end].iter().collect::<String>();
+    let mut caret = caret.map(|caret| caret - start);
+    if start > 0 {
+        snippet = format!("...{}", snippet);
+        caret = caret.map(|caret| caret + 3);
+    }
+    if end < chars.len() {
+        snippet += "...";
+    }
+
+    (snippet, caret)
+}
+
+// Why a single field (a number, a range) didn't parse, `offset` counting
+// from the start of the field.
+#[derive(Debug, PartialEq)]
+pub struct FieldError {
+    pub offset: usize,
+    pub message: String,
+    pub hint: Option<&'static str>,
+}
+
+impl FieldError {
+    fn new(offset: usize, message: String) -> Self {
+        FieldError {
+            offset,
+            message,
+            hint: None,
+        }
+    }
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solutions = match args.day {
+        Some(day) => vec![
+            registry::find(args.year, day)
+                .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?,
+        ],
+        None => registry::for_year(args.year),
+    };
+
+    if args.input.is_some() && solutions.len() != 1 {
+        return Err(String::from("--input needs a --day to lint against"));
+    }
+
+    let mut total = 0;
+    for solution in &solutions {
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| input::input_path(solution.year, solution.day));
+        let lines = read_lines(solution, &args.input)?;
+        let problems = check(solution, &lines);
+
+        if problems.is_empty() {
+            println!("{}: ok", path.display());
+        }
+        for problem in &problems {
+            println!("{}", problem.render(&path.display().to_string(), &lines));
+        }
+        total += problems.len();
+    }
+
+    if total > 0 {
+        return Err(format!("Found {} problem(s)", total));
+    }
+
+    Ok(())
+}
+
+fn read_lines(solution: &Solution, path: &Option<PathBuf>) -> Result<Vec<String>, String> {
+    let contents = match path {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|error| format!("Could not read {}: {}", path.display(), error))?,
+        None => input::read_input(solution.year, solution.day).map_err(|error| {
+            format!(
+                "Could not read {}: {}",
+                input::input_path(solution.year, solution.day).display(),
+                error
+            )
+        })?,
+    };
+
+    Ok(contents.lines().map(String::from).collect())
+}
+
+// Runs the day's lint, catching the empty input none of them expect.
+pub fn check(solution: &Solution, lines: &[String]) -> Vec<Problem> {
+    if lines.is_empty() {
+        return vec![Problem::new(1, String::from("input is empty"))];
+    }
+
+    (solution.lint)(lines)
+}
+
+// Checks that `text` is a non-empty run of ASCII digits small enough for an
+// i64, which is what the solvers parse it into.
+pub fn check_number(text: &str) -> Result<i64, FieldError> {
+    if text.is_empty() {
+        return Err(FieldError::new(
+            0,
+            String::from("expected a number, found nothing"),
+        ));
+    }
+    if let Some((offset, bad)) = text.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(FieldError::new(
+            offset,
+            format!("expected a digit, found '{}'", bad),
+        ));
+    }
+    text.parse::<i64>().map_err(|_| {
+        FieldError::new(
+            0,
+            format!("{} is too large, the largest is {}", text, i64::MAX),
+        )
+    })
+}
+
+// Checks that `text` is an `a-b` range with a <= b.
+pub fn check_range(text: &str) -> Result<(i64, i64), FieldError> {
+    let Some((start, end)) = text.split_once('-') else {
+        return Err(FieldError {
+            hint: Some("a range is two numbers joined by -"),
+            ..FieldError::new(0, format!("expected a range, found '{}'", text))
+        });
+    };
+
+    let end_offset = start.chars().count() + 1;
+    let start = check_number(start).map_err(|error| FieldError {
+        message: format!("range start: {}", error.message),
+        ..error
+    })?;
+    let end = check_number(end).map_err(|error| FieldError {
+        offset: end_offset + error.offset,
+        message: format!("range end: {}", error.message),
+        ..error
+    })?;
+
+    if start > end {
+        return Err(FieldError {
+            hint: Some("range end is less than start"),
+            ..FieldError::new(
+                end_offset,
+                format!("range {}-{} ends before it starts", start, end),
+            )
+        });
+    }
+
+    Ok((start, end))
+}
+
+// Checks that every line has the same length as the first, for the
+// rectangular inputs, pointing at where each odd line stops matching.
+pub fn check_width(lines: &[String], expected: &'static str, problems: &mut Vec<Problem>) {
+    let width = lines[0].chars().count();
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        let length = line.chars().count();
+        if length != width {
+            problems.push(
+                Problem::new(
+                    index + 1,
+                    format!(
+                        "expected {} characters like line 1, found {}",
+                        width, length
+                    ),
+                )
+                .at(length.min(width) + 1)
+                .expected(expected),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_number() {
+        assert_eq!(check_number("123"), Ok(123));
+        assert_eq!(check_number("").map_err(|error| error.offset), Err(0));
+        assert_eq!(check_number("12a").map_err(|error| error.offset), Err(2));
+        assert!(check_number("-1").is_err());
+        assert!(check_number("99999999999999999999999").is_err());
+        assert_eq!(check_number("9223372036854775807"), Ok(i64::MAX));
+        // é is two bytes but one column
+        assert_eq!(check_number("é1x").map_err(|error| error.offset), Err(0));
+        assert_eq!(check_number("1éx").map_err(|error| error.offset), Err(1));
+        assert!(check_number("9223372036854775808").is_err());
+    }
+
+    #[test]
+    fn test_check_range() {
+        assert_eq!(check_range("3-5"), Ok((3, 5)));
+        assert_eq!(check_range("5-5"), Ok((5, 5)));
+        assert_eq!(check_range("15-3").map_err(|error| error.offset), Err(3));
+        assert_eq!(
+            check_range("15-3").map_err(|error| error.hint),
+            Err(Some("range end is less than start"))
+        );
+        assert_eq!(check_range("3").map_err(|error| error.offset), Err(0));
+        assert_eq!(check_range("-5").map_err(|error| error.offset), Err(0));
+        assert_eq!(check_range("3-").map_err(|error| error.offset), Err(2));
+        assert_eq!(check_range("3-4x").map_err(|error| error.offset), Err(3));
+        assert_eq!(check_range("é-4").map_err(|error| error.offset), Err(0));
+        assert_eq!(check_range("1é-4").map_err(|error| error.offset), Err(1));
+    }
+
+    #[test]
+    fn test_check_width() {
+        let lines = ["abc", "abcd", "abc", "ab"].map(String::from);
+        let mut problems = Vec::new();
+        check_width(&lines, "three letters", &mut problems);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| (problem.line, problem.column))
+                .collect::<Vec<(usize, Option<usize>)>>(),
+            vec![(2, Some(4)), (4, Some(3))]
+        );
+
+        // é is two bytes but a single character
+        let lines = ["abc", "aéc", "aé", "aéc@"].map(String::from);
+        let mut problems = Vec::new();
+        check_width(&lines, "three letters", &mut problems);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| (problem.line, problem.column))
+                .collect::<Vec<(usize, Option<usize>)>>(),
+            vec![(3, Some(3)), (4, Some(4))]
+        );
+        assert_eq!(
+            problems[1].message,
+            "expected 3 characters like line 1, found 4"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let lines = ["L68", "X30"].map(String::from);
+        let problem = Problem::new(2, String::from("expected L or R, found 'X'"))
+            .at(1)
+            .expected("a rotation like L68")
+            .hint("direction must be L or R");
+
+        assert_eq!(
+            problem.render("inputs/2025/day_1.txt", &lines),
+            "error: expected L or R, found 'X'
+ --> inputs/2025/day_1.txt:2:1
+  |
+2 | X30
+  | ^
+  = expected: a rotation like L68
+  = hint: direction must be L or R
+"
+        );
+    }
+
+    #[test]
+    fn test_render_multibyte() {
+        let lines = [String::from("1é-4x")];
+        let error = check_range(&lines[0]).unwrap_err();
+        let problem = Problem::field(1, 1, error);
+
+        assert_eq!(
+            problem.render("input", &lines),
+            "error: range start: expected a digit, found 'é'
+ --> input:1:2
+  |
+1 | 1é-4x
+  |  ^
+"
+        );
+    }
+
+    #[test]
+    fn test_render_long_line() {
+        let line = format!("{}x{}", "1".repeat(100), "2".repeat(100));
+        let problem = Problem::new(1, String::from("bad")).at(101);
+        let rendered = problem.render("input", &[line]);
+        let rendered = rendered.lines().collect::<Vec<&str>>();
+
+        let snippet = rendered[3].trim_start_matches("1 | ");
+        let caret = rendered[4].trim_start_matches("  | ");
+        assert!(snippet.starts_with("...") && snippet.ends_with("..."));
+        assert_eq!(snippet.chars().nth(caret.len() - 1), Some('x'));
+    }
+}
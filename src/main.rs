@@ -1,18 +1,55 @@
-// mod day_1;
-// mod day_2;
-// mod day_3;
-// mod day_4;
-mod day_5;
-// use day_1::day_one;
-// use day_2::day_two;
-// use day_3::day_three;
-// use day_4::day_four;
-use day_5::day_five;
+use std::process;
+
+use advent_of_code_2025::{
+    anonymize, bench, cli, complexity, config, debug, encrypt, lint, report, runner, serve, stats,
+    tui,
+};
 
 fn main() {
-    // day_one(); // Day 1: Secret Entrance
-    // day_two(); // Day 2: Gift Shop
-    // day_three(); // Day 3: Lobby
-    // day_four(); // Day 4: Printing Department
-    day_five(); // Day 5: Cafeteria 
+    let argv = std::env::args().skip(1).collect::<Vec<String>>();
+    // parsed once for --config, then again on top of what the config sets
+    let config_path = match cli::parse_args(argv.iter().cloned()) {
+        Ok(args) => args.config,
+        Err(error) => {
+            eprintln!("{}\n{}", error, cli::USAGE);
+            process::exit(2);
+        }
+    };
+    let config = match config::load(config_path.as_deref()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
+    };
+    let args = match cli::parse_args_with(argv.into_iter(), config.defaults()) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, cli::USAGE);
+            process::exit(2);
+        }
+    };
+    config::init(config);
+
+    let result = match args.command {
+        cli::Command::Run => runner::run(&args),
+        cli::Command::Report => report::run(&args),
+        cli::Command::Lint => lint::run(&args),
+        cli::Command::Debug => debug::run(&args),
+        cli::Command::Bench(cli::BenchTarget::Parts) => bench::run_parts(&args),
+        cli::Command::Bench(cli::BenchTarget::Pairs) => bench::run_pairs(&args),
+        cli::Command::Bench(cli::BenchTarget::Compare) => bench::run_compare(&args),
+        cli::Command::EncryptInputs => encrypt::encrypt_inputs(&args),
+        cli::Command::DecryptInputs => encrypt::decrypt_inputs(&args),
+        cli::Command::Serve => serve::run(&args),
+        cli::Command::Tui => tui::run(&args),
+        cli::Command::Anonymize => anonymize::run(&args),
+        cli::Command::Stats => stats::run(&args),
+        cli::Command::Complexity => complexity::run(&args),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
 }
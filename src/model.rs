@@ -0,0 +1,182 @@
+// A day's input parsed once into the types its parts work on, so running
+// both parts doesn't parse twice. Models whose parse is the expensive bit can
+// also keep it on disk between runs, keyed by a hash of the input:
+//
+// .cache/2025/day_5/<hash>.txt
+use std::any;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config;
+
+pub trait Model: Sized + 'static {
+    fn parse(lines: &[String]) -> Self;
+
+    fn solve(&self, part: u8) -> String;
+}
+
+// A model that can write itself out and read itself back for the cache.
+pub trait Cached: Model {
+    // bumped whenever encode changes, so older cache files are parsed again
+    const VERSION: u32;
+
+    fn encode(&self) -> String;
+
+    // None for text that isn't a model, which is parsed again
+    fn decode(text: &str) -> Option<Self>;
+}
+
+// A parsed model of any day, for the runner.
+pub struct Parsed {
+    model: Box<dyn Solve>,
+    // read back from the cache rather than parsed
+    pub cached: bool,
+}
+
+impl Parsed {
+    pub fn solve(&self, part: u8) -> String {
+        self.model.solve(part)
+    }
+}
+
+// Model without the Sized parse, so it can be boxed.
+trait Solve {
+    fn solve(&self, part: u8) -> String;
+}
+
+impl<M: Model> Solve for M {
+    fn solve(&self, part: u8) -> String {
+        Model::solve(self, part)
+    }
+}
+
+// Parses every time, `cache` is only there to match parse_cached.
+pub fn parse<M: Model>(lines: &[String], _cache: Option<&Path>) -> Parsed {
+    Parsed {
+        model: Box::new(M::parse(lines)),
+        cached: false,
+    }
+}
+
+// Reads the model from `cache` when this input was parsed before, otherwise
+// parses it and writes it there for next time. A cache that can't be read or
+// written only means parsing.
+pub fn parse_cached<M: Cached>(lines: &[String], cache: Option<&Path>) -> Parsed {
+    let Some(directory) = cache else {
+        return parse::<M>(lines, None);
+    };
+    let path = directory.join(format!("{:016x}.txt", key::<M>(lines)));
+
+    if let Some(model) = fs::read_to_string(&path)
+        .ok()
+        .and_then(|text| M::decode(&text))
+    {
+        return Parsed {
+            model: Box::new(model),
+            cached: true,
+        };
+    }
+
+    let model = M::parse(lines);
+    let _ = fs::create_dir_all(directory).and_then(|_| fs::write(&path, model.encode()));
+    Parsed {
+        model: Box::new(model),
+        cached: false,
+    }
+}
+
+// .cache/2025/day_5
+pub fn cache_dir(year: u16, day: u8) -> PathBuf {
+    config::get()
+        .cache_dir
+        .join(year.to_string())
+        .join(format!("day_{}", day))
+}
+
+// FNV-1a over the model's name and version and then the input, so a changed
+// input or model misses rather than reading something stale.
+fn key<M: Cached>(lines: &[String]) -> u64 {
+    let header = format!("{} {}", any::type_name::<M>(), M::VERSION);
+
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for line in [header.as_str()]
+        .into_iter()
+        .chain(lines.iter().map(String::as_str))
+    {
+        for &byte in line.as_bytes().iter().chain(b"\n") {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the lines' total length, cached as that number
+    struct Length(usize);
+
+    impl Model for Length {
+        fn parse(lines: &[String]) -> Self {
+            Length(lines.iter().map(String::len).sum())
+        }
+
+        fn solve(&self, part: u8) -> String {
+            (self.0 * part as usize).to_string()
+        }
+    }
+
+    impl Cached for Length {
+        const VERSION: u32 = 1;
+
+        fn encode(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn decode(text: &str) -> Option<Self> {
+            text.parse().ok().map(Length)
+        }
+    }
+
+    #[test]
+    fn test_parse_cached() {
+        let directory = std::env::temp_dir().join(format!("aoc_model_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        let lines = ["abc", "de"].map(String::from);
+
+        let parsed = parse_cached::<Length>(&lines, Some(&directory));
+        assert!(!parsed.cached);
+        assert_eq!(parsed.solve(2), "10");
+
+        let parsed = parse_cached::<Length>(&lines, Some(&directory));
+        assert!(parsed.cached);
+        assert_eq!(parsed.solve(1), "5");
+
+        // a different input is a different file
+        let parsed = parse_cached::<Length>(&lines[..1], Some(&directory));
+        assert!(!parsed.cached);
+        assert_eq!(parsed.solve(1), "3");
+
+        // a file that doesn't decode is parsed again and overwritten
+        let path = directory.join(format!("{:016x}.txt", key::<Length>(&lines)));
+        fs::write(&path, "garbage").unwrap();
+        assert!(!parse_cached::<Length>(&lines, Some(&directory)).cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "5");
+
+        assert!(!parse_cached::<Length>(&lines, None).cached);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_key() {
+        let lines = ["ab", "c"].map(String::from);
+        assert_eq!(key::<Length>(&lines), key::<Length>(&lines.clone()));
+        // the line breaks count, not just the characters
+        assert_ne!(
+            key::<Length>(&lines),
+            key::<Length>(&["a", "bc"].map(String::from))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::solution::Solution;
+use crate::year_2025;
+
+// every year registers its own days, the registry just stitches them together
+pub fn solutions() -> Vec<Solution> {
+    let mut solutions = Vec::new();
+    solutions.extend(year_2025::solutions());
+    solutions
+}
+
+// Every year with a solution, oldest first, whatever order they registered in.
+pub fn years() -> Vec<u16> {
+    let mut years = solutions()
+        .iter()
+        .map(|solution| solution.year)
+        .collect::<Vec<u16>>();
+    // dedup only drops neighbours
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+pub fn find(year: u16, day: u8) -> Option<Solution> {
+    solutions()
+        .into_iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
+
+pub fn for_year(year: u16) -> Vec<Solution> {
+    solutions()
+        .into_iter()
+        .filter(|solution| solution.year == year)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_years() {
+        let years = years();
+        assert!(years.contains(&2025));
+        assert!(
+            years.windows(2).all(|pair| pair[0] < pair[1]),
+            "{:?}",
+            years
+        );
+    }
+
+    #[test]
+    fn test_examples_give_the_puzzle_answers() {
+        for solution in solutions() {
+            for (index, example) in solution.examples.iter().enumerate() {
+                for part in [1, 2] {
+                    if let Some(expected) = example.expected(part) {
+                        assert_eq!(
+                            (solution.part(part))(&example.lines()),
+                            expected,
+                            "{} day {} example {} part {}",
+                            solution.year,
+                            solution.day,
+                            index + 1,
+                            part
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cli::Args;
+use crate::registry;
+use crate::runner::{self, PartResult};
+use crate::solution::Solution;
+
+pub struct DayReport {
+    pub day: u8,
+    pub title: String,
+    // the error is kept so a missing input shows up in the report instead of aborting it
+    pub parts: Result<Vec<PartResult>, String>,
+    pub tests: usize,
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let reports = build(args.year);
+
+    let page = if args.html {
+        to_html(args.year, &reports, args.redact)
+    } else {
+        to_markdown(args.year, &reports, args.redact)
+    };
+
+    match &args.output {
+        Some(path) => fs::write(path, page)
+            .map_err(|error| format!("Could not write {}: {}", path.display(), error)),
+        None => {
+            print!("{}", page);
+            Ok(())
+        }
+    }
+}
+
+pub fn build(year: u16) -> Vec<DayReport> {
+    registry::for_year(year)
+        .iter()
+        .map(build_day)
+        .collect::<Vec<DayReport>>()
+}
+
+fn build_day(solution: &Solution) -> DayReport {
+    let parts = runner::read_input(solution).map(|lines| {
+        [1, 2]
+            .iter()
+            .map(|&part| runner::run_part(solution, part, &lines))
+            .collect::<Vec<PartResult>>()
+    });
+
+    DayReport {
+        day: solution.day,
+        title: solution.title.to_string(),
+        parts,
+        tests: count_tests(solution.year, solution.day),
+    }
+}
+
+// src/year_2025/day_1.rs
+fn source_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from("src")
+        .join(format!("year_{}", year))
+        .join(format!("day_{}.rs", day))
+}
+
+fn count_tests(year: u16, day: u8) -> usize {
+    let source = fs::read_to_string(source_path(year, day)).unwrap_or_default();
+    source
+        .lines()
+        .filter(|line| line.trim() == "#[test]")
+        .count()
+}
+
+fn status(result: &PartResult) -> &'static str {
+    match (&result.expected, result.is_correct()) {
+        (Some(_), true) => "★",
+        (Some(_), false) => "✗",
+        (None, _) => "?",
+    }
+}
+
+fn answer(result: &PartResult, redact: bool) -> String {
+    if redact {
+        String::from("[redacted]")
+    } else {
+        result.answer.clone()
+    }
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    format!("{:.2?}", elapsed)
+}
+
+fn count_stars(reports: &[DayReport]) -> usize {
+    reports
+        .iter()
+        .filter_map(|report| report.parts.as_ref().ok())
+        .flatten()
+        .filter(|result| result.is_correct())
+        .count()
+}
+
+pub fn to_markdown(year: u16, reports: &[DayReport], redact: bool) -> String {
+    let mut markdown = format!("# Advent of Code {}\n\n", year);
+
+    markdown.push_str("| Day | Title | Part 1 | Time | Part 2 | Time | Tests |\n");
+    markdown.push_str("|----:|-------|--------|-----:|--------|-----:|------:|\n");
+
+    for report in reports {
+        let mut row = format!("| {} | {} |", report.day, report.title);
+
+        match &report.parts {
+            Ok(parts) => {
+                for result in parts {
+                    row.push_str(&format!(
+                        " {} `{}` | {} |",
+                        status(result),
+                        answer(result, redact),
+                        format_duration(result.elapsed)
+                    ));
+                }
+            }
+            Err(error) => row.push_str(&format!(" {} | | | |", error)),
+        }
+
+        row.push_str(&format!(" {} |\n", report.tests));
+        markdown.push_str(&row);
+    }
+
+    markdown.push_str(&format!(
+        "\n★ {} of {} stars. ★ solved, ✗ wrong answer, ? no recorded answer.\n",
+        count_stars(reports),
+        reports.len() * 2
+    ));
+
+    markdown
+}
+
+pub fn to_html(year: u16, reports: &[DayReport], redact: bool) -> String {
+    let mut rows = String::new();
+
+    for report in reports {
+        rows.push_str(&format!(
+            "      <tr><td>{}</td><td>{}</td>",
+            report.day,
+            escape_html(&report.title)
+        ));
+
+        match &report.parts {
+            Ok(parts) => {
+                for result in parts {
+                    rows.push_str(&format!(
+                        "<td>{} <code>{}</code></td><td>{}</td>",
+                        status(result),
+                        escape_html(&answer(result, redact)),
+                        format_duration(result.elapsed)
+                    ));
+                }
+            }
+            Err(error) => rows.push_str(&format!("<td colspan=\"4\">{}</td>", escape_html(error))),
+        }
+
+        rows.push_str(&format!("<td>{}</td></tr>\n", report.tests));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>Advent of Code {year}</title>
+  <style>
+    body {{ font-family: sans-serif; margin: 2em; }}
+    table {{ border-collapse: collapse; }}
+    th, td {{ border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: left; }}
+    th {{ background: #f0f0f0; }}
+  </style>
+</head>
+<body>
+  <h1>Advent of Code {year}</h1>
+  <table>
+    <thead>
+      <tr><th>Day</th><th>Title</th><th>Part 1</th><th>Time</th><th>Part 2</th><th>Time</th><th>Tests</th></tr>
+    </thead>
+    <tbody>
+{rows}    </tbody>
+  </table>
+  <p>★ {stars} of {total} stars. ★ solved, ✗ wrong answer, ? no recorded answer.</p>
+</body>
+</html>
+"#,
+        year = year,
+        rows = rows,
+        stars = count_stars(reports),
+        total = reports.len() * 2
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(answer: &str, expected: Option<&str>) -> PartResult {
+        PartResult {
+            answer: answer.to_string(),
+            elapsed: Duration::from_millis(2),
+            expected: expected.map(String::from),
+        }
+    }
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 1,
+                title: String::from("Secret Entrance"),
+                parts: Ok(vec![part("3", Some("3")), part("7", Some("6"))]),
+                tests: 5,
+            },
+            DayReport {
+                day: 2,
+                title: String::from("Gift <Shop>"),
+                parts: Err(String::from("Could not read inputs/2025/day_2.txt")),
+                tests: 0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(2025, &reports(), false);
+        assert!(markdown.starts_with("# Advent of Code 2025\n"));
+        assert!(
+            markdown.contains("| 1 | Secret Entrance | ★ `3` | 2.00ms | ✗ `7` | 2.00ms | 5 |"),
+            "{}",
+            markdown
+        );
+        assert!(markdown.contains("| 2 | Gift <Shop> | Could not read inputs/2025/day_2.txt |"));
+        assert!(markdown.contains("★ 1 of 4 stars."));
+    }
+
+    #[test]
+    fn test_to_markdown_redacted() {
+        let markdown = to_markdown(2025, &reports(), true);
+        assert!(markdown.contains("★ `[redacted]`"));
+        assert!(!markdown.contains("`3`"));
+    }
+
+    #[test]
+    fn test_to_html() {
+        let html = to_html(2025, &reports(), false);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td>★ <code>3</code></td><td>2.00ms</td>"));
+        assert!(
+            html.contains("<td>Gift &lt;Shop&gt;</td>"),
+            "titles are escaped"
+        );
+        assert!(html.contains("★ 1 of 4 stars."));
+    }
+}
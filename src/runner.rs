@@ -0,0 +1,342 @@
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::cli::Args;
+use crate::lint;
+use crate::model;
+use crate::registry;
+use crate::solution::{Example, Solution};
+use crate::util::input;
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solutions = match args.day {
+        Some(day) => vec![
+            registry::find(args.year, day)
+                .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?,
+        ],
+        None => registry::for_year(args.year),
+    };
+
+    if solutions.is_empty() {
+        return Err(format!(
+            "No solutions registered for {}, available years: {:?}",
+            args.year,
+            registry::years()
+        ));
+    }
+
+    if args.input.is_some() && solutions.len() != 1 {
+        return Err(String::from("--input needs a --day to run against"));
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for solution in &solutions {
+        run_solution(solution, &parts, args)?;
+    }
+
+    Ok(())
+}
+
+fn run_solution(solution: &Solution, parts: &[u8], args: &Args) -> Result<(), String> {
+    let example = find_example(solution, args)?;
+
+    match args.example {
+        Some(number) => println!(
+            "--- {} Day {}: {} (example {}) ---",
+            solution.year, solution.day, solution.title, number
+        ),
+        None => println!(
+            "--- {} Day {}: {} ---",
+            solution.year, solution.day, solution.title
+        ),
+    }
+
+    if args.stream {
+        return run_streaming(solution, parts, args, example);
+    }
+    if args.bytes {
+        return run_bytes(solution, parts, args, example);
+    }
+
+    let lines = read_lines(solution, args, example)?;
+
+    // parsed once for both parts
+    let start = Instant::now();
+    let parsed = (solution.parse)(&lines, cache_dir(solution, args, example).as_deref());
+    println!(
+        "Parsed in {:.2?}{}",
+        start.elapsed(),
+        if parsed.cached { " (cached)" } else { "" }
+    );
+
+    for &part in parts {
+        if args.explain {
+            (solution.explain)(&lines, part, &mut |line| println!("{}", line));
+        }
+
+        let start = Instant::now();
+        let answer = parsed.solve(part);
+        let result = PartResult {
+            answer,
+            elapsed: start.elapsed(),
+            expected: expected_answer(solution, part, args, example),
+        };
+        print_result(part, &result, example.is_some());
+    }
+
+    Ok(())
+}
+
+// Where the day may cache its parse, None for the worked examples and for
+// --no-cache. Also None for an input decrypted in memory, since a cached
+// parse of it would put it back on the disk.
+fn cache_dir(solution: &Solution, args: &Args, example: Option<&Example>) -> Option<PathBuf> {
+    let on_disk = args.input.is_some() || input::input_path(solution.year, solution.day).exists();
+    (example.is_none() && on_disk && !args.no_cache)
+        .then(|| model::cache_dir(solution.year, solution.day))
+}
+
+// The worked example picked with --example, if any.
+pub fn find_example(solution: &Solution, args: &Args) -> Result<Option<&'static Example>, String> {
+    match args.example {
+        Some(number) => Ok(Some(solution.examples.get(number - 1).ok_or_else(
+            || {
+                format!(
+                    "{} day {} has {} example(s), there is no example {}",
+                    solution.year,
+                    solution.day,
+                    solution.examples.len(),
+                    number
+                )
+            },
+        )?)),
+        None => Ok(None),
+    }
+}
+
+// Reads the example, the --input file or the real input, in that order, and
+// lints it.
+pub fn read_lines(
+    solution: &Solution,
+    args: &Args,
+    example: Option<&Example>,
+) -> Result<Vec<String>, String> {
+    let (source, lines) = match (example, &args.input) {
+        (Some(example), _) => (
+            format!("example {}", args.example.unwrap_or(1)),
+            example.lines(),
+        ),
+        (None, Some(path)) => (
+            path.display().to_string(),
+            fs::read_to_string(path)
+                .map_err(|error| format!("Could not read {}: {}", path.display(), error))?
+                .lines()
+                .map(String::from)
+                .collect(),
+        ),
+        (None, None) => (
+            input::input_path(solution.year, solution.day)
+                .display()
+                .to_string(),
+            read_input(solution)?,
+        ),
+    };
+
+    check_lines(solution, &source, &lines)?;
+    Ok(lines)
+}
+
+// a bad line would only panic somewhere deep in the solver, so check first
+fn check_lines(solution: &Solution, source: &str, lines: &[String]) -> Result<(), String> {
+    let problems = lint::check(solution, lines);
+    if !problems.is_empty() {
+        for problem in &problems {
+            eprintln!("{}", problem.render(source, lines));
+        }
+        return Err(format!(
+            "Could not parse {}: found {} problem(s)",
+            source,
+            problems.len()
+        ));
+    }
+
+    Ok(())
+}
+
+// Feeds the input to the day's streaming solver a line at a time, so inputs
+// bigger than memory still run. An input that is only kept encrypted is
+// decrypted into memory first, the same as run does, and streamed from there.
+fn run_streaming(
+    solution: &Solution,
+    parts: &[u8],
+    args: &Args,
+    example: Option<&Example>,
+) -> Result<(), String> {
+    let stream = solution.stream.ok_or_else(|| {
+        format!(
+            "{} day {} needs its whole input at once and can't stream it",
+            solution.year, solution.day
+        )
+    })?;
+    let path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| input::input_path(solution.year, solution.day));
+    let decrypted = match example {
+        None if args.input.is_none() && !path.exists() => Some(
+            input::read_input(solution.year, solution.day)
+                .map_err(|error| format!("Could not read {}: {}", path.display(), error))?,
+        ),
+        _ => None,
+    };
+
+    for &part in parts {
+        let mut reader: Box<dyn BufRead> = match (example, &decrypted) {
+            (Some(example), _) => Box::new(example.input.as_bytes()),
+            (None, Some(text)) => Box::new(text.as_bytes()),
+            (None, None) => {
+                Box::new(BufReader::new(File::open(&path).map_err(|error| {
+                    format!("Could not read {}: {}", path.display(), error)
+                })?))
+            }
+        };
+
+        let start = Instant::now();
+        let answer = stream(&mut reader, part)
+            .map_err(|error| format!("Could not parse {}: {}", path.display(), error))?;
+        let result = PartResult {
+            answer,
+            elapsed: start.elapsed(),
+            expected: expected_answer(solution, part, args, example),
+        };
+        print_result(part, &result, example.is_some());
+    }
+
+    Ok(())
+}
+
+// Hands the day's byte solver the whole input as one slice, memory-mapped
+// when built with the mmap feature.
+fn run_bytes(
+    solution: &Solution,
+    parts: &[u8],
+    args: &Args,
+    example: Option<&Example>,
+) -> Result<(), String> {
+    let solve_bytes = solution
+        .solve_bytes
+        .ok_or_else(|| format!("{} day {} has no byte solver", solution.year, solution.day))?;
+
+    let (source, bytes) = match (example, &args.input) {
+        (Some(example), _) => (
+            format!("example {}", args.example.unwrap_or(1)),
+            input::InputBytes::Owned(example.input.as_bytes().to_vec()),
+        ),
+        (None, Some(path)) => (
+            path.display().to_string(),
+            input::read_bytes_from(path)
+                .map_err(|error| format!("Could not read {}: {}", path.display(), error))?,
+        ),
+        (None, None) => {
+            let path = input::input_path(solution.year, solution.day);
+            let bytes = input::read_bytes(solution.year, solution.day)
+                .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+            (path.display().to_string(), bytes)
+        }
+    };
+
+    // the byte solvers trust their input as much as the others do, so it's
+    // linted the same way, before the clock starts
+    let text = std::str::from_utf8(&bytes)
+        .map_err(|error| format!("Could not parse {}: {}", source, error))?;
+    let lines = text.lines().map(String::from).collect::<Vec<String>>();
+    check_lines(solution, &source, &lines)?;
+    drop(lines);
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = solve_bytes(&bytes, part);
+        let result = PartResult {
+            answer,
+            elapsed: start.elapsed(),
+            expected: expected_answer(solution, part, args, example),
+        };
+        print_result(part, &result, example.is_some());
+    }
+
+    Ok(())
+}
+
+fn expected_answer(
+    solution: &Solution,
+    part: u8,
+    args: &Args,
+    example: Option<&Example>,
+) -> Option<String> {
+    match (example, &args.input) {
+        (Some(example), _) => example.expected(part),
+        // there's no recorded answer for someone else's input
+        (None, Some(_)) => None,
+        (None, None) => input::read_answer(solution.year, solution.day, part),
+    }
+}
+
+fn print_result(part: u8, result: &PartResult, example: bool) {
+    let check = match (&result.expected, result.is_correct()) {
+        // the example answer is always shown, it's what you compare against by eye
+        (Some(expected), true) if example => format!(" ✓ example answer {}", expected),
+        (Some(_), true) => String::from(" ✓"),
+        (Some(expected), false) => format!(" ✗ expected {}", expected),
+        (None, _) => String::new(),
+    };
+
+    println!(
+        "Part {}: {} ({:.2?}){}",
+        part, result.answer, result.elapsed, check
+    );
+}
+
+pub fn read_input(solution: &Solution) -> Result<Vec<String>, String> {
+    input::read_lines(solution.year, solution.day).map_err(|error| {
+        format!(
+            "Could not read {}: {}",
+            input::input_path(solution.year, solution.day).display(),
+            error
+        )
+    })
+}
+
+pub struct PartResult {
+    pub answer: String,
+    pub elapsed: Duration,
+    // the recorded answer from answers/<year>.txt, if there is one
+    pub expected: Option<String>,
+}
+
+impl PartResult {
+    pub fn is_correct(&self) -> bool {
+        self.expected.as_ref() == Some(&self.answer)
+    }
+}
+
+pub fn run_part(solution: &Solution, part: u8, lines: &[String]) -> PartResult {
+    let (answer, elapsed) = time_part(solution, part, lines);
+
+    PartResult {
+        answer,
+        elapsed,
+        expected: input::read_answer(solution.year, solution.day, part),
+    }
+}
+
+pub fn time_part(solution: &Solution, part: u8, lines: &[String]) -> (String, Duration) {
+    let start = Instant::now();
+    let answer = (solution.part(part))(lines);
+    (answer, start.elapsed())
+}
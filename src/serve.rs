@@ -0,0 +1,607 @@
+// A small HTTP service so tools can ask for answers without shelling out.
+//
+//   POST /<year>/day/<day>/part/<part>   body is the puzzle input
+//   GET  /solutions                      lists what is registered
+//
+// Answers come back as JSON. Request lines, headers and bodies are capped,
+// inputs are linted before any solver sees them, and every solve runs on its
+// own thread with a deadline. A solver that overruns is reported as a timeout,
+// though the thread itself can't be stopped and finishes in the background,
+// holding its slot until it does. When every slot is taken the server answers
+// 503 rather than starting more threads.
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cli::Args;
+use crate::lint;
+use crate::registry;
+use crate::util::json;
+
+pub const DEFAULT_PORT: u16 = 8025;
+pub const DEFAULT_TIMEOUT_SECS: u64 = 10;
+pub const DEFAULT_MAX_BODY: usize = 1024 * 1024;
+const MAX_HEADER_LINES: usize = 100;
+// the longest request line or header, in bytes
+const MAX_LINE: usize = 8 * 1024;
+const MAX_CONNECTIONS: usize = 64;
+const MAX_SOLVERS: usize = 16;
+
+static CONNECTIONS: Slots = Slots::new(MAX_CONNECTIONS);
+static SOLVERS: Slots = Slots::new(MAX_SOLVERS);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub timeout: Duration,
+    pub max_body: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: String) -> Response {
+        Response { status, body }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response::json(status, format!("{{\"error\":{}}}", json::string(message)))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            414 => "URI Too Long",
+            422 => "Unprocessable Entity",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let port = args.port.unwrap_or(DEFAULT_PORT);
+    let config = Config {
+        timeout: Duration::from_secs(args.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS)),
+        max_body: args.max_body.unwrap_or(DEFAULT_MAX_BODY),
+    };
+
+    // localhost only, this is for tools on the same machine
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|error| format!("Could not listen on port {}: {}", port, error))?;
+    println!("Listening on http://127.0.0.1:{}", port);
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => match CONNECTIONS.acquire() {
+                Some(slot) => {
+                    thread::spawn(move || {
+                        let _slot = slot;
+                        handle_connection(stream, config)
+                    });
+                }
+                None => {
+                    let response = Response::error(503, "too many connections, try again later");
+                    write_response(stream, &response);
+                }
+            },
+            Err(error) => eprintln!("Connection failed: {}", error),
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_connection(mut stream: TcpStream, config: Config) {
+    // slow or stalled clients shouldn't hold a thread forever
+    let _ = stream.set_read_timeout(Some(config.timeout));
+
+    let response = match read_request(&mut stream, config.max_body) {
+        Ok(request) => handle_request(&request, config),
+        Err(response) => response,
+    };
+
+    write_response(stream, &response);
+}
+
+fn write_response(mut stream: TcpStream, response: &Response) {
+    // nor should clients that stop reading
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)));
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    );
+}
+
+pub fn read_request(stream: impl Read, max_body: usize) -> Result<Request, Response> {
+    let mut reader = BufReader::new(stream);
+    let bad_request = |message: &str| Response::error(400, message);
+
+    let request_line = read_line(&mut reader).map_err(|error| match error {
+        LineError::TooLong => Response::error(
+            414,
+            &format!("request line is longer than {} bytes", MAX_LINE),
+        ),
+        LineError::Unreadable => bad_request("could not read request"),
+    })?;
+
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(bad_request("malformed request line")),
+    };
+
+    let mut content_length = None;
+    for _ in 0..MAX_HEADER_LINES {
+        let header = read_line(&mut reader).map_err(|error| match error {
+            LineError::TooLong => {
+                Response::error(431, &format!("a header is longer than {} bytes", MAX_LINE))
+            }
+            LineError::Unreadable => bad_request("could not read headers"),
+        })?;
+
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = Some(
+                value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| bad_request("invalid Content-Length"))?,
+            );
+        }
+    }
+
+    let body = match (method.as_str(), content_length) {
+        ("POST", None) => return Err(Response::error(411, "Content-Length is required")),
+        (_, Some(length)) if length > max_body => {
+            return Err(Response::error(
+                413,
+                &format!("input is {} bytes, the limit is {}", length, max_body),
+            ));
+        }
+        (_, Some(length)) => {
+            let mut body = vec![0u8; length];
+            reader
+                .read_exact(&mut body)
+                .map_err(|_| bad_request("body shorter than Content-Length"))?;
+            String::from_utf8(body).map_err(|_| bad_request("input must be UTF-8"))?
+        }
+        (_, None) => String::new(),
+    };
+
+    Ok(Request { method, path, body })
+}
+
+enum LineError {
+    TooLong,
+    Unreadable,
+}
+
+// Reads up to and including the next \n, giving up past MAX_LINE bytes so a
+// client can't grow the buffer without end.
+fn read_line(reader: &mut impl BufRead) -> Result<String, LineError> {
+    let mut line = String::new();
+    reader
+        .by_ref()
+        .take(MAX_LINE as u64)
+        .read_line(&mut line)
+        .map_err(|_| LineError::Unreadable)?;
+
+    if line.len() == MAX_LINE && !line.ends_with('\n') {
+        return Err(LineError::TooLong);
+    }
+    Ok(line)
+}
+
+pub fn handle_request(request: &Request, config: Config) -> Response {
+    let segments = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<&str>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["solutions"]) => list_solutions(),
+        ("POST", [year, "day", day, "part", part]) => {
+            match (year.parse::<u16>(), day.parse::<u8>(), part.parse::<u8>()) {
+                (Ok(year), Ok(day), Ok(part @ 1..=2)) => {
+                    solve(year, day, part, &request.body, config)
+                }
+                _ => Response::error(404, "expected /<year>/day/<day>/part/<1|2>"),
+            }
+        }
+        (_, ["solutions"]) | (_, [_, "day", _, "part", _]) => {
+            Response::error(405, "method not allowed")
+        }
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn list_solutions() -> Response {
+    let solutions = registry::solutions()
+        .iter()
+        .map(|solution| {
+            format!(
+                "{{\"year\":{},\"day\":{},\"title\":{}}}",
+                solution.year,
+                solution.day,
+                json::string(solution.title)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    Response::json(200, format!("[{}]", solutions.join(",")))
+}
+
+fn solve(year: u16, day: u8, part: u8, input: &str, config: Config) -> Response {
+    let solution = match registry::find(year, day) {
+        Some(solution) => solution,
+        None => {
+            return Response::error(
+                404,
+                &format!("no solution registered for {} day {}", year, day),
+            );
+        }
+    };
+
+    let lines = input.lines().map(String::from).collect::<Vec<String>>();
+
+    // a bad line would only panic somewhere deep in the solver, so check first
+    let problems = lint::check(&solution, &lines);
+    if !problems.is_empty() {
+        return invalid_input(&problems);
+    }
+
+    match solve_with_timeout(solution.part(part), lines, config.timeout, &SOLVERS) {
+        Ok((answer, elapsed)) => Response::json(
+            200,
+            format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3}}}",
+                year,
+                day,
+                part,
+                json::string(&answer),
+                elapsed.as_secs_f64() * 1000.0
+            ),
+        ),
+        Err(SolveError::Timeout) => Response::error(
+            504,
+            &format!("solver took longer than {:?}", config.timeout),
+        ),
+        Err(SolveError::Panicked) => Response::error(422, "solver failed on this input"),
+        Err(SolveError::Busy) => Response::error(503, "every solver is busy, try again later"),
+    }
+}
+
+fn invalid_input(problems: &[lint::Problem]) -> Response {
+    let problems = problems
+        .iter()
+        .map(|problem| {
+            let column = match problem.column {
+                Some(column) => column.to_string(),
+                None => String::from("null"),
+            };
+            format!(
+                "{{\"line\":{},\"column\":{},\"message\":{}}}",
+                problem.line,
+                column,
+                json::string(&problem.message)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    Response::json(
+        400,
+        format!(
+            "{{\"error\":{},\"problems\":[{}]}}",
+            json::string(&format!("input has {} problem(s)", problems.len())),
+            problems.join(",")
+        ),
+    )
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    Timeout,
+    Panicked,
+    Busy,
+}
+
+// Runs `solver` on a thread of its own, which holds one of `slots` until the
+// solver returns, deadline or not.
+pub fn solve_with_timeout(
+    solver: fn(&[String]) -> String,
+    lines: Vec<String>,
+    timeout: Duration,
+    slots: &'static Slots,
+) -> Result<(String, Duration), SolveError> {
+    let slot = slots.acquire().ok_or(SolveError::Busy)?;
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _slot = slot;
+        let start = Instant::now();
+        let answer = solver(&lines);
+        let _ = sender.send((answer, start.elapsed()));
+    });
+
+    receiver.recv_timeout(timeout).map_err(|error| match error {
+        RecvTimeoutError::Timeout => SolveError::Timeout,
+        // the sender is dropped without sending when the solver panics
+        RecvTimeoutError::Disconnected => SolveError::Panicked,
+    })
+}
+
+// Counts threads against a limit, each held until its Slot drops.
+pub struct Slots {
+    used: AtomicUsize,
+    limit: usize,
+}
+
+pub struct Slot(&'static Slots);
+
+impl Slots {
+    pub const fn new(limit: usize) -> Slots {
+        Slots {
+            used: AtomicUsize::new(0),
+            limit,
+        }
+    }
+
+    pub fn acquire(&'static self) -> Option<Slot> {
+        self.used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                (used < self.limit).then_some(used + 1)
+            })
+            .ok()
+            .map(|_| Slot(self))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.used.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: Config = Config {
+        timeout: Duration::from_secs(5),
+        max_body: 64,
+    };
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /2025/day/1/part/1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 8\r\n\r\nL68\nL30\n";
+        assert_eq!(
+            read_request(raw.as_bytes(), 64),
+            Ok(request("POST", "/2025/day/1/part/1", "L68\nL30\n"))
+        );
+    }
+
+    #[test]
+    fn test_read_request_limits() {
+        let too_big = "POST /2025/day/1/part/1 HTTP/1.1\r\nContent-Length: 65\r\n\r\n";
+        assert_eq!(
+            read_request(too_big.as_bytes(), 64).unwrap_err().status,
+            413
+        );
+
+        let no_length = "POST /2025/day/1/part/1 HTTP/1.1\r\n\r\n";
+        assert_eq!(
+            read_request(no_length.as_bytes(), 64).unwrap_err().status,
+            411
+        );
+
+        let short_body = "POST /2025/day/1/part/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\nL68";
+        assert_eq!(
+            read_request(short_body.as_bytes(), 64).unwrap_err().status,
+            400
+        );
+
+        let long_path = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE));
+        assert_eq!(
+            read_request(long_path.as_bytes(), 64).unwrap_err().status,
+            414
+        );
+
+        // one endless header with no line break
+        let long_header = format!(
+            "GET /solutions HTTP/1.1\r\nX-Padding: {}",
+            "a".repeat(MAX_LINE)
+        );
+        assert_eq!(
+            read_request(long_header.as_bytes(), 64).unwrap_err().status,
+            431
+        );
+    }
+
+    #[test]
+    fn test_handle_request_routes() {
+        assert_eq!(
+            handle_request(&request("GET", "/nope", ""), CONFIG).status,
+            404
+        );
+        assert_eq!(
+            handle_request(&request("GET", "/2025/day/1/part/1", ""), CONFIG).status,
+            405
+        );
+        assert_eq!(
+            handle_request(&request("POST", "/2025/day/1/part/3", ""), CONFIG).status,
+            404
+        );
+        assert_eq!(
+            handle_request(&request("POST", "/1999/day/1/part/1", ""), CONFIG).status,
+            404
+        );
+        assert_eq!(
+            handle_request(&request("GET", "/solutions", ""), CONFIG).status,
+            200
+        );
+    }
+
+    #[cfg(feature = "year_2025_day_1")]
+    #[test]
+    fn test_handle_request_solves() {
+        let example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let response = handle_request(&request("POST", "/2025/day/1/part/2", example), CONFIG);
+
+        assert_eq!(response.status, 200);
+        assert!(
+            response.body.starts_with(
+                "{\"year\":2025,\"day\":1,\"part\":2,\"answer\":\"6\",\"elapsed_ms\":"
+            ),
+            "{}",
+            response.body
+        );
+    }
+
+    #[cfg(feature = "year_2025_day_1")]
+    #[test]
+    fn test_handle_request_lints() {
+        let response = handle_request(&request("POST", "/2025/day/1/part/1", "L68\nX30\n"), CONFIG);
+
+        assert_eq!(response.status, 400);
+        assert!(
+            response
+                .body
+                .starts_with("{\"error\":\"input has 1 problem(s)\",\"problems\":[{\"line\":2,"),
+            "{}",
+            response.body
+        );
+
+        let empty = handle_request(&request("POST", "/2025/day/1/part/1", ""), CONFIG);
+        assert_eq!(empty.status, 400);
+    }
+
+    fn slow(_lines: &[String]) -> String {
+        thread::sleep(Duration::from_millis(200));
+        String::from("late")
+    }
+
+    fn broken(_lines: &[String]) -> String {
+        panic!("Invalid direction: X");
+    }
+
+    #[test]
+    fn test_solve_with_timeout() {
+        static SLOTS: Slots = Slots::new(4);
+
+        assert_eq!(
+            solve_with_timeout(slow, Vec::new(), Duration::from_millis(10), &SLOTS),
+            Err(SolveError::Timeout)
+        );
+        assert_eq!(
+            solve_with_timeout(broken, Vec::new(), Duration::from_secs(1), &SLOTS),
+            Err(SolveError::Panicked)
+        );
+        assert_eq!(
+            solve_with_timeout(slow, Vec::new(), Duration::from_secs(5), &SLOTS)
+                .map(|(answer, _)| answer),
+            Ok(String::from("late"))
+        );
+    }
+
+    #[test]
+    fn test_solve_with_timeout_busy() {
+        static SLOTS: Slots = Slots::new(1);
+
+        // the overrunning solver keeps its slot after the deadline
+        assert_eq!(
+            solve_with_timeout(slow, Vec::new(), Duration::from_millis(10), &SLOTS),
+            Err(SolveError::Timeout)
+        );
+        assert_eq!(
+            solve_with_timeout(slow, Vec::new(), Duration::from_secs(5), &SLOTS),
+            Err(SolveError::Busy)
+        );
+
+        // and gives it back once it finishes
+        thread::sleep(Duration::from_millis(400));
+        assert!(solve_with_timeout(slow, Vec::new(), Duration::from_secs(5), &SLOTS).is_ok());
+    }
+
+    #[test]
+    fn test_slots() {
+        static SLOTS: Slots = Slots::new(2);
+
+        let first = SLOTS.acquire();
+        let second = SLOTS.acquire();
+        assert!(first.is_some() && second.is_some());
+        assert!(SLOTS.acquire().is_none());
+
+        drop(first);
+        assert!(SLOTS.acquire().is_some());
+    }
+
+    #[test]
+    fn test_handle_connection() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            handle_connection(stream, CONFIG);
+        });
+
+        let mut client = TcpStream::connect(address).unwrap();
+        client
+            .write_all(b"GET /missing HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+
+        assert!(
+            response.starts_with("HTTP/1.1 404 Not Found\r\n"),
+            "{}",
+            response
+        );
+        assert!(
+            response.ends_with("{\"error\":\"not found\"}"),
+            "{}",
+            response
+        );
+    }
+}
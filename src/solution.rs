@@ -0,0 +1,101 @@
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use crate::debug::Step;
+use crate::lint::Problem;
+use crate::model::Parsed;
+use crate::util::generate::Rng;
+
+// Parses the input into the day's model once, for solving both parts from.
+// Days that cache their parse keep it in the directory given, when there is
+// one. See crate::model.
+pub type Parse = fn(&[String], Option<&Path>) -> Parsed;
+
+// Solves one part while narrating each step through the callback, for
+// --explain. Returns the same answer as the plain part function.
+pub type Explain = fn(&[String], u8, &mut dyn FnMut(String)) -> String;
+
+// Solves one part while reporting each step of the simulation through the
+// callback, for the debugger. Returns the same answer as the plain part
+// function.
+pub type Trace = fn(&[String], u8, &mut dyn FnMut(Step)) -> String;
+
+// Solves one part reading the input a line at a time, for inputs too big
+// to hold in memory. Returns the same answer as the plain part function.
+pub type Stream = fn(&mut dyn BufRead, u8) -> io::Result<String>;
+
+// Solves one part straight from the input's bytes, without a String per
+// line. Returns the same answer as the plain part function.
+pub type SolveBytes = fn(&[u8], u8) -> String;
+
+// Rewrites an input into a different one with the same answers, e.g. the grid
+// mirrored, so it can be shared without giving away the original. The random
+// choices come from the generator so a seed reproduces them.
+pub type Anonymize = fn(&[String], &mut Rng) -> Vec<String>;
+
+// Makes a random input of about `size` bytes, for measuring how the parts'
+// times grow with their input. Each day grows whatever its real inputs have
+// more of, e.g. longer banks for day 3. See crate::complexity.
+pub type Generate = fn(usize, &mut Rng) -> Vec<String>;
+
+/// A single day's puzzle solution as registered with the runner.
+///
+/// Both parts take the puzzle input as lines and return the answer formatted
+/// for display, so the runner can treat every day (and every year) the same way.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub part_one: fn(&[String]) -> String,
+    pub part_two: fn(&[String]) -> String,
+    pub parse: Parse,
+    pub explain: Explain,
+    // Only the simulation-style days can be stepped through.
+    pub debug: Option<Trace>,
+    // Only the days whose answer can be worked out line by line stream.
+    pub stream: Option<Stream>,
+    // Only the days that gain from skipping the per-line Strings have one.
+    pub solve_bytes: Option<SolveBytes>,
+    // Only the days with a transformation known to keep the answers have one.
+    pub anonymize: Option<Anonymize>,
+    // Only the days with a generator can have their scaling checked.
+    pub generate: Option<Generate>,
+    // Checks the input against the day's grammar without solving it,
+    // reporting every problem rather than stopping at the first.
+    pub lint: fn(&[String]) -> Vec<Problem>,
+    // The worked examples from the puzzle text, for --example.
+    pub examples: &'static [Example],
+}
+
+// An example input stored next to the day, with the answers the puzzle text
+// gives for it. A part is None when the puzzle doesn't use this example for it.
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    pub fn lines(&self) -> Vec<String> {
+        self.input.lines().map(String::from).collect()
+    }
+
+    pub fn expected(&self, part: u8) -> Option<String> {
+        match part {
+            1 => self.part_one,
+            2 => self.part_two,
+            _ => panic!("Invalid part: {}", part),
+        }
+        .map(String::from)
+    }
+}
+
+impl Solution {
+    pub fn part(&self, part: u8) -> fn(&[String]) -> String {
+        match part {
+            1 => self.part_one,
+            2 => self.part_two,
+            _ => panic!("Invalid part: {}", part),
+        }
+    }
+}
@@ -0,0 +1,492 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::cli::Args;
+use crate::config;
+use crate::registry;
+use crate::util::json::{self, Value};
+
+// Each puzzle unlocks at midnight US Eastern time, 05:00 UTC.
+const UNLOCK_HOUR_UTC: u64 = 5;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// Shows a private leaderboard saved from adventofcode.com (the JSON API link
+// on the leaderboard's page) next to the days this crate has solved. Nothing
+// is fetched, the export is read from --input or paths.leaderboard.
+pub fn run(args: &Args) -> Result<(), String> {
+    let path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| config::get().leaderboard_file.clone());
+    let text = fs::read_to_string(&path)
+        .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+    let leaderboard = Leaderboard::parse(&text, &path.display().to_string())?;
+
+    let implemented = registry::for_year(leaderboard.event)
+        .iter()
+        .map(|solution| (solution.day, solution.title))
+        .collect::<Vec<(u8, &str)>>();
+    let page = to_markdown(&leaderboard, &implemented);
+
+    match &args.output {
+        Some(path) => fs::write(path, page)
+            .map_err(|error| format!("Could not write {}: {}", path.display(), error)),
+        None => {
+            print!("{}", page);
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Leaderboard {
+    pub event: u16,
+    // when day 1 unlocked, in seconds since the epoch
+    pub day1_ts: u64,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    // when each part's star came in, by day
+    pub completions: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+impl Leaderboard {
+    // `source` names the file in errors.
+    pub fn parse(text: &str, source: &str) -> Result<Leaderboard, String> {
+        let root = json::parse(text).map_err(|error| {
+            format!(
+                "{}:{}:{}: {}",
+                source, error.line, error.column, error.message
+            )
+        })?;
+        Leaderboard::from_json(&root).map_err(|error| format!("{}: {}", source, error))
+    }
+
+    fn from_json(root: &Value) -> Result<Leaderboard, String> {
+        // "2025" in the exports, but a number reads just as well
+        let event = field(root, "", "event")?;
+        let event = event
+            .as_str()
+            .and_then(|event| event.parse().ok())
+            .or_else(|| event.as_u64().and_then(|event| u16::try_from(event).ok()))
+            .ok_or_else(|| format!("event must be a year, found {}", event.kind()))?;
+
+        // older exports don't have day1_ts
+        let day1_ts = match root.get("day1_ts") {
+            Some(value) => number(value, "day1_ts")?,
+            None => unlock_of_day_one(event),
+        };
+
+        let members = field(root, "", "members")?;
+        let members = members
+            .as_object()
+            .ok_or_else(|| format!("members must be an object, found {}", members.kind()))?
+            .iter()
+            .map(|(id, member)| Member::from_json(member, &format!("members.{}", id)))
+            .collect::<Result<Vec<Member>, String>>()?;
+
+        Ok(Leaderboard {
+            event,
+            day1_ts,
+            members,
+        })
+    }
+
+    // When `day` unlocked, in seconds since the epoch.
+    pub fn unlock(&self, day: u8) -> u64 {
+        self.day1_ts + (day as u64 - 1) * SECONDS_PER_DAY
+    }
+}
+
+impl Member {
+    fn from_json(member: &Value, path: &str) -> Result<Member, String> {
+        let id = number(field(member, path, "id")?, &format!("{}.id", path))?;
+        // members who haven't picked a name show up as null
+        let name = match field(member, path, "name")? {
+            Value::Null => format!("(anonymous user #{})", id),
+            Value::String(name) => name.clone(),
+            other => {
+                return Err(format!(
+                    "{}.name must be a string, found {}",
+                    path,
+                    other.kind()
+                ));
+            }
+        };
+
+        let local_score = number(
+            field(member, path, "local_score")?,
+            &format!("{}.local_score", path),
+        )?;
+        let stars = number(field(member, path, "stars")?, &format!("{}.stars", path))?;
+
+        let mut completions = BTreeMap::new();
+        let days = field(member, path, "completion_day_level")?;
+        let path = format!("{}.completion_day_level", path);
+        let days = days
+            .as_object()
+            .ok_or_else(|| format!("{} must be an object, found {}", path, days.kind()))?;
+        for (day, parts) in days {
+            let path = format!("{}.{}", path, day);
+            let day = day
+                .parse::<u8>()
+                .ok()
+                .filter(|&day| day >= 1)
+                .ok_or_else(|| format!("{} is not a day", path))?;
+
+            let mut stars = [None, None];
+            for (part, star) in stars.iter_mut().enumerate() {
+                let part = (part + 1).to_string();
+                if let Some(level) = parts.get(&part) {
+                    let path = format!("{}.{}", path, part);
+                    *star = Some(number(field(level, &path, "get_star_ts")?, &path)?);
+                }
+            }
+            completions.insert(day, stars);
+        }
+
+        Ok(Member {
+            id,
+            name,
+            local_score,
+            stars,
+            completions,
+        })
+    }
+}
+
+fn field<'a>(value: &'a Value, path: &str, key: &str) -> Result<&'a Value, String> {
+    value.get(key).ok_or_else(|| match path {
+        "" => format!("{} is missing", key),
+        path => format!("{}.{} is missing", path, key),
+    })
+}
+
+fn number(value: &Value, path: &str) -> Result<u64, String> {
+    value
+        .as_u64()
+        .ok_or_else(|| format!("{} must be a whole number, found {}", path, value.kind()))
+}
+
+// December 1st of `year` at 05:00 UTC.
+fn unlock_of_day_one(year: u16) -> u64 {
+    let is_leap = |year: u64| {
+        (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+    };
+
+    let days = (1970..year as u64)
+        .map(|year| if is_leap(year) { 366 } else { 365 })
+        .sum::<u64>()
+        // January to November
+        + if is_leap(year as u64) { 335 } else { 334 };
+    days * SECONDS_PER_DAY + UNLOCK_HOUR_UTC * 60 * 60
+}
+
+// `implemented` is the days this crate solves with their titles.
+pub fn to_markdown(leaderboard: &Leaderboard, implemented: &[(u8, &str)]) -> String {
+    let mut members = leaderboard.members.iter().collect::<Vec<&Member>>();
+    members.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.name.cmp(&b.name))
+    });
+
+    let mut days = members
+        .iter()
+        .flat_map(|member| member.completions.keys().copied())
+        .chain(implemented.iter().map(|&(day, _)| day))
+        .collect::<Vec<u8>>();
+    days.sort();
+    days.dedup();
+
+    let mut markdown = format!(
+        "# Advent of Code {} private leaderboard\n\n",
+        leaderboard.event
+    );
+
+    markdown.push_str("| Member | Score | Stars |");
+    for day in &days {
+        markdown.push_str(&format!(" {} |", day));
+    }
+    markdown.push_str("\n|--------|------:|------:|");
+    markdown.push_str(&":-:|".repeat(days.len()));
+    markdown.push('\n');
+
+    for member in &members {
+        let mut row = format!(
+            "| {} | {} | {} |",
+            escape_cell(&member.name),
+            member.local_score,
+            member.stars
+        );
+        for day in &days {
+            row.push_str(match member.completions.get(day) {
+                Some([Some(_), Some(_)]) => " ★★ |",
+                Some([Some(_), None]) => " ★ |",
+                _ => " |",
+            });
+        }
+        markdown.push_str(&row);
+        markdown.push('\n');
+    }
+
+    let mut row = String::from("| *implemented here* | | |");
+    for day in &days {
+        let is_implemented = implemented.iter().any(|&(other, _)| other == *day);
+        row.push_str(if is_implemented { " ✓ |" } else { " |" });
+    }
+    markdown.push_str(&row);
+    markdown.push('\n');
+
+    for &day in &days {
+        markdown.push_str(&day_section(leaderboard, &members, day, implemented));
+    }
+
+    markdown.push_str(
+        "\n★★ both parts, ★ part 1 only, ✓ solved by this crate. Times run from the \
+         puzzle unlocking to each star, the delta from part 1's star to part 2's.\n",
+    );
+
+    markdown
+}
+
+fn day_section(
+    leaderboard: &Leaderboard,
+    members: &[&Member],
+    day: u8,
+    implemented: &[(u8, &str)],
+) -> String {
+    let mut markdown = match implemented.iter().find(|&&(other, _)| other == day) {
+        Some((_, title)) => format!("\n## Day {}: {}\n\nImplemented here.\n", day, title),
+        None => format!("\n## Day {}\n\nNot implemented here yet.\n", day),
+    };
+
+    let unlock = leaderboard.unlock(day);
+    // None for a star from before the unlock, which only a hand-edited
+    // export or a wrong day1_ts gives
+    let since_unlock = |timestamp: u64| timestamp.checked_sub(unlock);
+
+    let mut finishers = members
+        .iter()
+        .filter_map(|member| {
+            let [Some(first), second] = *member.completions.get(&day)? else {
+                return None;
+            };
+            Some((member, first, second))
+        })
+        .collect::<Vec<(&&Member, u64, Option<u64>)>>();
+    if finishers.is_empty() {
+        markdown.push_str("\nNo stars yet.\n");
+        return markdown;
+    }
+    // fastest to both stars first, then the ones still on part 2
+    finishers.sort_by_key(|&(member, first, second)| {
+        (
+            second.and_then(since_unlock).unwrap_or(u64::MAX),
+            since_unlock(first).unwrap_or(u64::MAX),
+            &member.name,
+        )
+    });
+
+    markdown.push_str("\n| Member | Part 1 | Part 2 | Delta |\n");
+    markdown.push_str("|--------|-------:|-------:|------:|\n");
+    for (member, first, second) in finishers {
+        markdown.push_str(&format!(
+            "| {} | {} |",
+            escape_cell(&member.name),
+            format_elapsed(since_unlock(first))
+        ));
+        markdown.push_str(&match second {
+            Some(second) => format!(
+                " {} | {} |\n",
+                format_elapsed(since_unlock(second)),
+                format_elapsed(second.checked_sub(first))
+            ),
+            None => String::from(" | |\n"),
+        });
+    }
+
+    markdown
+}
+
+// 1:02:03, with the hours running past a day rather than wrapping.
+fn format_time(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+// A time that can't be worked out, e.g. a star before its day unlocked, is -.
+fn format_elapsed(seconds: Option<u64>) -> String {
+    seconds.map_or_else(|| String::from("-"), format_time)
+}
+
+// Names are whatever members typed, so a | can't end their cell early.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // day 1 unlocks at 1764565200, day 2 at 1764651600
+    const EXPORT: &str = r#"{
+  "event": "2025",
+  "owner_id": 1,
+  "day1_ts": 1764565200,
+  "members": {
+    "1": {
+      "id": 1, "name": "Ada", "stars": 3, "local_score": 10, "last_star_ts": 1764652000,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1764565500, "star_index": 1},
+              "2": {"get_star_ts": 1764566100, "star_index": 2}},
+        "2": {"1": {"get_star_ts": 1764652000, "star_index": 3}}
+      }
+    },
+    "2": {
+      "id": 2, "name": null, "stars": 2, "local_score": 12, "last_star_ts": 1764569000,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1764565260, "star_index": 4},
+              "2": {"get_star_ts": 1764569000, "star_index": 5}}
+      }
+    },
+    "3": {
+      "id": 3, "name": "Grace | Hopper", "stars": 0, "local_score": 0, "last_star_ts": 0,
+      "completion_day_level": {}
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(EXPORT, "leaderboard.json").unwrap();
+        assert_eq!(leaderboard.event, 2025);
+        assert_eq!(leaderboard.unlock(2), 1764651600);
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let ada = &leaderboard.members[0];
+        assert_eq!((ada.id, ada.name.as_str(), ada.stars), (1, "Ada", 3));
+        assert_eq!(
+            ada.completions.get(&1),
+            Some(&[Some(1764565500), Some(1764566100)])
+        );
+        assert_eq!(ada.completions.get(&2), Some(&[Some(1764652000), None]));
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Leaderboard::parse("{\"event\": }", "leaderboard.json").err(),
+            Some(String::from(
+                "leaderboard.json:1:11: expected a value, found '}'"
+            ))
+        );
+        assert_eq!(
+            Leaderboard::parse("{\"event\": \"2025\"}", "leaderboard.json").err(),
+            Some(String::from("leaderboard.json: members is missing"))
+        );
+        assert_eq!(
+            Leaderboard::parse(
+                &EXPORT.replace("\"stars\": 2", "\"stars\": \"2\""),
+                "leaderboard.json"
+            )
+            .err(),
+            Some(String::from(
+                "leaderboard.json: members.2.stars must be a whole number, found a string"
+            ))
+        );
+        assert_eq!(
+            Leaderboard::parse(
+                &EXPORT.replace("\"get_star_ts\": 1764652000", "\"ts\": 1"),
+                "leaderboard.json"
+            )
+            .err(),
+            Some(String::from(
+                "leaderboard.json: members.1.completion_day_level.2.1.get_star_ts is missing"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unlock_without_day1_ts() {
+        assert_eq!(unlock_of_day_one(2025), 1764565200);
+        assert_eq!(unlock_of_day_one(2024), 1733029200);
+
+        let leaderboard = Leaderboard::parse(
+            &EXPORT.replace("\"day1_ts\": 1764565200,", ""),
+            "leaderboard.json",
+        )
+        .unwrap();
+        assert_eq!(leaderboard.day1_ts, 1764565200);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let leaderboard = Leaderboard::parse(EXPORT, "leaderboard.json").unwrap();
+        let markdown = to_markdown(&leaderboard, &[(1, "Secret Entrance"), (3, "Lobby")]);
+
+        assert!(markdown.starts_with("# Advent of Code 2025 private leaderboard\n"));
+        assert!(
+            markdown.contains("| Member | Score | Stars | 1 | 2 | 3 |\n"),
+            "{}",
+            markdown
+        );
+        // by score, so the anonymous member leads
+        assert!(markdown.contains(
+            "| (anonymous user #2) | 12 | 2 | ★★ | | |\n\
+             | Ada | 10 | 3 | ★★ | ★ | |\n\
+             | Grace \\| Hopper | 0 | 0 | | | |\n\
+             | *implemented here* | | | ✓ | | ✓ |\n"
+        ));
+
+        // Ada got part 2 first even though part 1 took longer
+        assert!(markdown.contains(
+            "## Day 1: Secret Entrance\n\nImplemented here.\n\n\
+             | Member | Part 1 | Part 2 | Delta |\n\
+             |--------|-------:|-------:|------:|\n\
+             | Ada | 0:05:00 | 0:15:00 | 0:10:00 |\n\
+             | (anonymous user #2) | 0:01:00 | 1:03:20 | 1:02:20 |\n"
+        ));
+        assert!(markdown.contains("## Day 2\n\nNot implemented here yet.\n"));
+        assert!(markdown.contains("| Ada | 0:06:40 | | |\n"));
+        assert!(markdown.contains("## Day 3: Lobby\n\nImplemented here.\n\nNo stars yet.\n"));
+    }
+
+    #[test]
+    fn test_star_before_unlock() {
+        // Ada's day 1 stars moved to before day 1 unlocked
+        let export = EXPORT
+            .replace("1764565500", "1764565000")
+            .replace("1764566100", "1764565100");
+        let leaderboard = Leaderboard::parse(&export, "leaderboard.json").unwrap();
+        let markdown = to_markdown(&leaderboard, &[]);
+
+        assert!(
+            markdown.contains(
+                "| (anonymous user #2) | 0:01:00 | 1:03:20 | 1:02:20 |\n\
+                 | Ada | - | - | 0:01:40 |\n"
+            ),
+            "{}",
+            markdown
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0), "0:00:00");
+        assert_eq!(format_time(3723), "1:02:03");
+        assert_eq!(format_time(30 * 3600 + 5), "30:00:05");
+    }
+}
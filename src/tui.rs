@@ -0,0 +1,538 @@
+mod terminal;
+
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::cli::Args;
+use crate::lint;
+use crate::registry;
+use crate::runner::{self, PartResult};
+use crate::solution::Solution;
+use crate::util::fixtures::{self, Fixture};
+use crate::util::input;
+use terminal::Key;
+
+const HELP: &str =
+    " ↑↓ day  ←→ input  1 2 run part  r run both  e explain  PgUp PgDn scroll  q quit";
+
+const REVERSE: &str = "\x1b[7m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+// Where a run reads its input from, cycled through with ← and →.
+enum Source {
+    Real,
+    Example(usize),
+    Fixture(Fixture),
+}
+
+struct Day {
+    solution: Solution,
+    sources: Vec<Source>,
+    source: usize,
+    // the last run of each part against the current source
+    results: [Option<PartResult>; 2],
+    // parts with a recorded answer in answers/<year>.txt
+    stars: usize,
+}
+
+impl Day {
+    fn new(solution: Solution) -> Self {
+        let mut sources = vec![Source::Real];
+        sources.extend((0..solution.examples.len()).map(Source::Example));
+        // the worked examples are fixtures too, no need to list them twice
+        for fixture in fixtures::discover(solution.day).unwrap_or_default() {
+            if !solution
+                .examples
+                .iter()
+                .any(|example| example.input == fixture.input)
+            {
+                sources.push(Source::Fixture(fixture));
+            }
+        }
+
+        let stars = [1, 2]
+            .iter()
+            .filter(|&&part| input::read_answer(solution.year, solution.day, part).is_some())
+            .count();
+
+        Day {
+            solution,
+            sources,
+            source: 0,
+            results: [None, None],
+            stars,
+        }
+    }
+
+    fn source_name(&self) -> String {
+        match &self.sources[self.source] {
+            Source::Real => input::input_path(self.solution.year, self.solution.day)
+                .display()
+                .to_string(),
+            Source::Example(index) => format!("example {}", index + 1),
+            Source::Fixture(fixture) => format!("fixture {}", fixture.name),
+        }
+    }
+
+    fn lines(&self) -> Result<Vec<String>, String> {
+        match &self.sources[self.source] {
+            Source::Real => runner::read_input(&self.solution),
+            Source::Example(index) => Ok(self.solution.examples[*index].lines()),
+            Source::Fixture(fixture) => Ok(fixture.lines()),
+        }
+    }
+
+    // The answer to check against: recorded for the real input, given with
+    // an example or fixture.
+    fn expected(&self, part: u8) -> Option<String> {
+        match &self.sources[self.source] {
+            Source::Real => input::read_answer(self.solution.year, self.solution.day, part),
+            Source::Example(index) => self.solution.examples[*index].expected(part),
+            Source::Fixture(fixture) => match part {
+                1 => fixture.part_one.clone(),
+                _ => fixture.part_two.clone(),
+            },
+        }
+    }
+}
+
+// Something slow the event loop shows a status for before doing.
+#[derive(Debug, PartialEq)]
+enum Work {
+    Run(Vec<u8>),
+    Explain,
+}
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Redraw,
+    Quit,
+    Work(Work),
+}
+
+struct App {
+    year: u16,
+    days: Vec<Day>,
+    selected: usize,
+    // explain output, run results or whatever went wrong
+    pane: Vec<String>,
+    scroll: usize,
+    status: String,
+}
+
+impl App {
+    fn new(year: u16, solutions: Vec<Solution>) -> Self {
+        App {
+            year,
+            days: solutions.into_iter().map(Day::new).collect(),
+            selected: 0,
+            pane: Vec::new(),
+            scroll: 0,
+            status: String::from("Press r to run the selected day"),
+        }
+    }
+
+    fn day(&self) -> &Day {
+        &self.days[self.selected]
+    }
+
+    fn handle(&mut self, key: Key, pane_height: usize) -> Action {
+        let page = pane_height.max(1);
+
+        match key {
+            Key::Char('q') | Key::Escape | Key::Interrupt => return Action::Quit,
+            Key::Up | Key::Char('k') => self.select(self.selected.saturating_sub(1)),
+            Key::Down | Key::Char('j') => self.select(self.selected + 1),
+            Key::Left | Key::Right | Key::Tab | Key::Char('i') => {
+                let day = &mut self.days[self.selected];
+                let count = day.sources.len();
+                day.source = match key {
+                    Key::Left => (day.source + count - 1) % count,
+                    _ => (day.source + 1) % count,
+                };
+                day.results = [None, None];
+                self.status = format!("Input: {}", day.source_name());
+            }
+            Key::Char('1') => return Action::Work(Work::Run(vec![1])),
+            Key::Char('2') => return Action::Work(Work::Run(vec![2])),
+            Key::Char('r') | Key::Enter => return Action::Work(Work::Run(vec![1, 2])),
+            Key::Char('e') => return Action::Work(Work::Explain),
+            Key::PageUp => self.scroll = self.scroll.saturating_sub(page),
+            Key::PageDown | Key::Char(' ') => {
+                self.scroll = (self.scroll + page).min(self.pane.len().saturating_sub(1))
+            }
+            Key::Home => self.scroll = 0,
+            Key::End => self.scroll = self.pane.len().saturating_sub(page),
+            _ => {}
+        }
+
+        Action::Redraw
+    }
+
+    fn select(&mut self, index: usize) {
+        if index < self.days.len() && index != self.selected {
+            self.selected = index;
+            self.pane.clear();
+            self.scroll = 0;
+            self.status = format!("Input: {}", self.day().source_name());
+        }
+    }
+
+    fn describe(&self, work: &Work) -> String {
+        let solution = &self.day().solution;
+        match work {
+            Work::Run(parts) if parts.len() == 1 => {
+                format!("Running day {} part {}...", solution.day, parts[0])
+            }
+            Work::Run(_) => format!("Running day {}...", solution.day),
+            Work::Explain => format!("Explaining day {}...", solution.day),
+        }
+    }
+
+    fn perform(&mut self, work: Work) {
+        self.scroll = 0;
+
+        let lines = match self.checked_lines() {
+            Ok(lines) => lines,
+            Err(pane) => {
+                self.pane = pane;
+                self.status = format!("Could not read {}", self.day().source_name());
+                return;
+            }
+        };
+
+        match work {
+            Work::Run(parts) => {
+                self.pane.clear();
+                for part in parts {
+                    let day = &self.days[self.selected];
+                    let ran = catch(|| runner::run_part(&day.solution, part, &lines));
+                    match ran {
+                        Ok(mut result) => {
+                            result.expected = day.expected(part);
+                            self.pane
+                                .push(format!("Part {}: {}", part, result_cell(&result)));
+                            self.days[self.selected].results[part as usize - 1] = Some(result);
+                        }
+                        Err(message) => self
+                            .pane
+                            .push(format!("Part {} panicked: {}", part, message)),
+                    }
+                }
+                self.status = format!("Ran {}", self.day().source_name());
+            }
+            Work::Explain => {
+                let explain = self.day().solution.explain;
+                let mut pane = Vec::new();
+                for part in [1, 2] {
+                    pane.push(format!("--- Part {} ---", part));
+                    let explained = catch(|| {
+                        let mut narration = Vec::new();
+                        let answer = explain(&lines, part, &mut |line| {
+                            narration.extend(line.lines().map(String::from))
+                        });
+                        (narration, answer)
+                    });
+                    match explained {
+                        Ok((narration, answer)) => {
+                            pane.extend(narration);
+                            pane.push(format!("Answer: {}", answer));
+                        }
+                        Err(message) => pane.push(format!("panicked: {}", message)),
+                    }
+                    pane.push(String::new());
+                }
+                self.pane = pane;
+                self.status = format!("Explained {}", self.day().source_name());
+            }
+        }
+    }
+
+    // The input's lines, or the lint problems rendered for the pane.
+    fn checked_lines(&self) -> Result<Vec<String>, Vec<String>> {
+        let day = self.day();
+        let lines = day.lines().map_err(|error| vec![error])?;
+
+        let problems = lint::check(&day.solution, &lines);
+        if !problems.is_empty() {
+            return Err(problems
+                .iter()
+                .flat_map(|problem| {
+                    problem
+                        .render(&day.source_name(), &lines)
+                        .lines()
+                        .map(String::from)
+                        .collect::<Vec<String>>()
+                })
+                .collect());
+        }
+
+        Ok(lines)
+    }
+
+    // The rows the pane gets once the table and chrome are drawn.
+    fn pane_height(&self, rows: usize) -> usize {
+        rows.saturating_sub(self.days.len() + 7)
+    }
+
+    // The whole screen, one string per row, each cut to `columns`.
+    fn render(&self, rows: usize, columns: usize) -> Vec<String> {
+        let mut screen = Vec::new();
+        let rule = "─".repeat(columns);
+
+        screen.push(styled(
+            REVERSE,
+            &fit(&format!(" Advent of Code {}", self.year), columns),
+        ));
+        screen.push(fit(
+            &format!(
+                "   {:>3}  {:<24} {:<5} {:<28} {:<28}",
+                "Day", "Title", "Stars", "Part 1", "Part 2"
+            ),
+            columns,
+        ));
+        for (index, day) in self.days.iter().enumerate() {
+            let cell = |part: usize| {
+                day.results[part]
+                    .as_ref()
+                    .map(result_cell)
+                    .unwrap_or_default()
+            };
+            let row = fit(
+                &format!(
+                    " {} {:>3}  {:<24} {:<5} {:<28} {:<28}",
+                    if index == self.selected { '>' } else { ' ' },
+                    day.solution.day,
+                    day.solution.title,
+                    "★".repeat(day.stars),
+                    cell(0),
+                    cell(1)
+                ),
+                columns,
+            );
+            screen.push(if index == self.selected {
+                styled(REVERSE, &row)
+            } else {
+                row
+            });
+        }
+
+        let day = self.day();
+        screen.push(rule.clone());
+        screen.push(fit(
+            &format!(
+                " Input: {} ({}/{})",
+                day.source_name(),
+                day.source + 1,
+                day.sources.len()
+            ),
+            columns,
+        ));
+        screen.push(rule);
+
+        let pane_height = self.pane_height(rows);
+        for index in self.scroll..self.scroll + pane_height {
+            screen.push(fit(
+                &format!(" {}", self.pane.get(index).map_or("", String::as_str)),
+                columns,
+            ));
+        }
+
+        screen.push(fit(&format!(" {}", self.status), columns));
+        screen.push(styled(DIM, &fit(HELP, columns)));
+        screen.truncate(rows);
+        screen
+    }
+}
+
+// "997 (1.23ms) ✓", with ✗ for a wrong answer and no mark when unknown.
+fn result_cell(result: &PartResult) -> String {
+    let mark = match &result.expected {
+        Some(_) if result.is_correct() => " ✓",
+        Some(_) => " ✗",
+        None => "",
+    };
+    format!("{} ({:.2?}){}", result.answer, result.elapsed, mark)
+}
+
+// Pads or cuts `text` to exactly `width` characters.
+fn fit(text: &str, width: usize) -> String {
+    let mut fitted = text.chars().take(width).collect::<String>();
+    let length = fitted.chars().count();
+    fitted.extend(std::iter::repeat_n(' ', width - length));
+    fitted
+}
+
+fn styled(style: &str, text: &str) -> String {
+    format!("{}{}{}", style, text, RESET)
+}
+
+// Runs a solver, turning a panic into its message so one bad input doesn't
+// take the dashboard (and the terminal's raw mode) down with it.
+fn catch<T>(solve: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("unknown panic"))
+    })
+}
+
+fn draw(screen: &[String]) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    // home, then every row over the last frame, raw mode needs the \r
+    write!(stdout, "\x1b[H{}", screen.join("\r\n"))?;
+    stdout.flush()
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solutions = registry::for_year(args.year);
+    if solutions.is_empty() {
+        return Err(format!(
+            "No solutions registered for {}, available years: {:?}",
+            args.year,
+            registry::years()
+        ));
+    }
+
+    let mut app = App::new(args.year, solutions);
+    if let Some(day) = args.day {
+        app.select(
+            app.days
+                .iter()
+                .position(|candidate| candidate.solution.day == day)
+                .ok_or_else(|| format!("No solution registered for {} day {}", args.year, day))?,
+        );
+    }
+
+    let raw_mode = terminal::RawMode::enable()?;
+    // panics are caught and shown in the pane, the default hook would print
+    // over the screen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = event_loop(&mut app);
+    panic::set_hook(hook);
+    drop(raw_mode);
+
+    result.map_err(|error| format!("Could not drive the terminal: {}", error))
+}
+
+fn event_loop(app: &mut App) -> io::Result<()> {
+    loop {
+        let (rows, columns) = terminal::size();
+        draw(&app.render(rows, columns))?;
+
+        for key in terminal::read_keys()? {
+            match app.handle(key, app.pane_height(rows)) {
+                Action::Quit => return Ok(()),
+                Action::Redraw => {}
+                Action::Work(work) => {
+                    app.status = app.describe(&work);
+                    draw(&app.render(rows, columns))?;
+                    app.perform(work);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        App::new(2025, registry::for_year(2025))
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut app = app();
+        assert_eq!(app.handle(Key::Up, 10), Action::Redraw);
+        assert_eq!(app.selected, 0);
+
+        for _ in 0..app.days.len() + 3 {
+            app.handle(Key::Down, 10);
+        }
+        assert_eq!(app.selected, app.days.len() - 1);
+
+        app.handle(Key::Right, 10);
+        assert_eq!(app.day().source, 1);
+        app.handle(Key::Left, 10);
+        app.handle(Key::Left, 10);
+        assert_eq!(app.day().source, app.day().sources.len() - 1);
+
+        assert_eq!(
+            app.handle(Key::Char('2'), 10),
+            Action::Work(Work::Run(vec![2]))
+        );
+        assert_eq!(app.handle(Key::Char('e'), 10), Action::Work(Work::Explain));
+        assert_eq!(app.handle(Key::Interrupt, 10), Action::Quit);
+    }
+
+    #[cfg(feature = "year_2025_day_1")]
+    #[test]
+    fn test_run_example() {
+        let mut app = app();
+        app.handle(Key::Right, 10);
+        assert!(matches!(
+            app.day().sources[app.day().source],
+            Source::Example(0)
+        ));
+
+        app.perform(Work::Run(vec![1, 2]));
+        let results = &app.day().results;
+        assert!(
+            results
+                .iter()
+                .all(|result| result.as_ref().is_some_and(PartResult::is_correct))
+        );
+        assert_eq!(app.pane.len(), 2);
+
+        app.perform(Work::Explain);
+        assert_eq!(app.pane[0], "--- Part 1 ---");
+        assert!(app.pane.iter().any(|line| line == "Answer: 3"));
+    }
+
+    #[cfg(feature = "year_2025_day_1")]
+    #[test]
+    fn test_render() {
+        let mut app = app();
+        app.days[0].stars = 2;
+        app.days[0].results[0] = Some(PartResult {
+            answer: String::from("997"),
+            elapsed: std::time::Duration::from_micros(1500),
+            expected: Some(String::from("997")),
+        });
+        app.pane = vec![String::from("Part 1: 997 (1.50ms) ✓")];
+
+        let screen = app.render(app.days.len() + 10, 60);
+        assert_eq!(screen.len(), app.days.len() + 10);
+        assert_eq!(
+            screen[2],
+            styled(
+                REVERSE,
+                " >   1  Secret Entrance          ★★    997 (1.50ms) ✓       "
+            )
+        );
+        assert_eq!(
+            screen[app.days.len() + 5].trim_end(),
+            " Part 1: 997 (1.50ms) ✓"
+        );
+        assert!(
+            screen
+                .iter()
+                .all(|row| row.chars().filter(|&c| c != '\x1b').count() >= 60)
+        );
+    }
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 1), Ok(1));
+        assert_eq!(
+            catch(|| -> u8 { panic!("Invalid rotation X30") }),
+            Err(String::from("Invalid rotation X30"))
+        );
+    }
+}
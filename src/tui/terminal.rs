@@ -0,0 +1,176 @@
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+
+// Alternate screen on, cursor hidden, and back again.
+const ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const LEAVE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+
+// Puts the terminal in raw mode on the alternate screen until dropped, which
+// also happens when unwinding from a panic. Goes through stty rather than
+// binding termios for the two calls it needs.
+pub struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    pub fn enable() -> Result<RawMode, String> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+
+        let mut stdout = io::stdout();
+        write!(stdout, "{}", ENTER_SCREEN)
+            .and_then(|_| stdout.flush())
+            .map_err(|error| format!("Could not write to the terminal: {}", error))?;
+
+        Ok(RawMode {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        let _ = write!(stdout, "{}", LEAVE_SCREEN);
+        let _ = stdout.flush();
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|error| format!("Could not run stty: {}", error))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "stty {} failed, the dashboard needs a terminal: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+// Rows and columns, 24x80 when stty can't tell.
+pub fn size() -> (usize, usize) {
+    stty(&["size"])
+        .ok()
+        .and_then(|size| {
+            let (rows, columns) = size.trim().split_once(' ')?;
+            Some((rows.parse().ok()?, columns.parse().ok()?))
+        })
+        .filter(|&(rows, columns)| rows > 0 && columns > 0)
+        .unwrap_or((24, 80))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Enter,
+    Tab,
+    Escape,
+    // raw mode turns Ctrl-C into a byte rather than a signal
+    Interrupt,
+    Char(char),
+}
+
+// Blocks until there is input, then returns every key in it.
+pub fn read_keys() -> io::Result<Vec<Key>> {
+    let mut buffer = [0; 64];
+    let read = io::stdin().read(&mut buffer)?;
+    Ok(parse_keys(&buffer[..read]))
+}
+
+pub fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let (key, length) = match bytes[index..] {
+            [0x1b, b'[' | b'O', code, ..] if code.is_ascii_uppercase() => {
+                let key = match code {
+                    b'A' => Some(Key::Up),
+                    b'B' => Some(Key::Down),
+                    b'C' => Some(Key::Right),
+                    b'D' => Some(Key::Left),
+                    b'H' => Some(Key::Home),
+                    b'F' => Some(Key::End),
+                    _ => None,
+                };
+                (key, 3)
+            }
+            [0x1b, b'[', number, b'~', ..] => {
+                let key = match number {
+                    b'1' | b'7' => Some(Key::Home),
+                    b'4' | b'8' => Some(Key::End),
+                    b'5' => Some(Key::PageUp),
+                    b'6' => Some(Key::PageDown),
+                    _ => None,
+                };
+                (key, 4)
+            }
+            [0x1b, ..] => (Some(Key::Escape), 1),
+            [3, ..] => (Some(Key::Interrupt), 1),
+            [b'\r' | b'\n', ..] => (Some(Key::Enter), 1),
+            [b'\t', ..] => (Some(Key::Tab), 1),
+            [byte, ..] if byte.is_ascii() => {
+                let key = (!byte.is_ascii_control()).then_some(Key::Char(byte as char));
+                (key, 1)
+            }
+            _ => {
+                // a multi-byte character, as long as its lead byte says
+                let length = match bytes[index] {
+                    0xc0..=0xdf => 2,
+                    0xe0..=0xef => 3,
+                    _ => 4,
+                }
+                .min(bytes.len() - index);
+                let key = std::str::from_utf8(&bytes[index..index + length])
+                    .ok()
+                    .and_then(|text| text.chars().next())
+                    .map(Key::Char);
+                (key, length)
+            }
+        };
+
+        keys.extend(key);
+        index += length;
+    }
+
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            parse_keys(b"\x1b[A\x1b[B\x1bOCq\r"),
+            vec![Key::Up, Key::Down, Key::Right, Key::Char('q'), Key::Enter]
+        );
+        assert_eq!(
+            parse_keys(b"\x1b[5~\x1b[6~\x1b[H\x1b[4~"),
+            vec![Key::PageUp, Key::PageDown, Key::Home, Key::End]
+        );
+        assert_eq!(parse_keys(b"\x1b"), vec![Key::Escape]);
+        assert_eq!(parse_keys(b"\x03\t"), vec![Key::Interrupt, Key::Tab]);
+        assert_eq!(
+            parse_keys("é1".as_bytes()),
+            vec![Key::Char('é'), Key::Char('1')]
+        );
+        // unknown sequences are skipped whole rather than typed out
+        assert_eq!(parse_keys(b"\x1b[Z\x1b[9~x"), vec![Key::Char('x')]);
+    }
+}
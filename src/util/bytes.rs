@@ -0,0 +1,79 @@
+// Zero-copy iteration over an input held as bytes, e.g. a memory-mapped
+// file. Every line and field is a slice of the original buffer.
+
+// Lines without their \n (or \r\n), like str::lines.
+pub fn lines(bytes: &[u8]) -> Lines<'_> {
+    Lines { rest: bytes }
+}
+
+pub struct Lines<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (line, rest) = match self.rest.iter().position(|&byte| byte == b'\n') {
+            Some(end) => (&self.rest[..end], &self.rest[end + 1..]),
+            None => (self.rest, &self.rest[self.rest.len()..]),
+        };
+        self.rest = rest;
+
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+// The parts of a line between separators, e.g. the ranges on day 2's line.
+pub fn fields(line: &[u8], separator: u8) -> impl Iterator<Item = &[u8]> {
+    line.split(move |&byte| byte == separator)
+}
+
+// A run of ASCII digits, None for anything else (including nothing).
+pub fn parse_number(field: &[u8]) -> Option<u64> {
+    if field.is_empty() {
+        return None;
+    }
+
+    field.iter().try_fold(0u64, |number, &byte| {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        number.checked_mul(10)?.checked_add((byte - b'0') as u64)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let text = "a\r\nb\n\nc";
+        assert_eq!(
+            lines(text.as_bytes()).collect::<Vec<&[u8]>>(),
+            text.lines().map(str::as_bytes).collect::<Vec<&[u8]>>()
+        );
+        assert_eq!(lines(b"a\nb\n").count(), 2);
+        assert_eq!(lines(b"").count(), 0);
+    }
+
+    #[test]
+    fn test_fields_and_numbers() {
+        let ranges = fields(b"11-22,95-115", b',')
+            .map(|range| {
+                let mut ends = fields(range, b'-').map(parse_number);
+                (ends.next().flatten(), ends.next().flatten())
+            })
+            .collect::<Vec<(Option<u64>, Option<u64>)>>();
+        assert_eq!(ranges, vec![(Some(11), Some(22)), (Some(95), Some(115))]);
+
+        assert_eq!(parse_number(b""), None);
+        assert_eq!(parse_number(b"1a"), None);
+        assert_eq!(parse_number(b"99999999999999999999999"), None);
+    }
+}
@@ -0,0 +1,237 @@
+// ChaCha20 (RFC 8439) for keeping puzzle inputs encrypted at rest.
+//
+// An encrypted file is MAGIC, a 12 byte nonce, then the ciphertext of
+// CHECK followed by the input. CHECK lets a wrong key be reported as such
+// rather than producing garbage, but there is no MAC: this keeps inputs from
+// being readable in the repository, it doesn't detect tampering.
+use std::fs;
+use std::io::Read;
+
+use crate::config;
+
+pub const MAGIC: &[u8; 8] = b"AOCENC01";
+const CHECK: &[u8; 8] = b"AOCPLAIN";
+const NONCE_LEN: usize = 12;
+
+// where the key is kept unless paths.key says otherwise, never committed
+pub const KEY_FILE: &str = ".aoc_key";
+
+pub type Key = [u8; 32];
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+fn block(key: &Key, counter: u32, nonce: &[u8; NONCE_LEN]) -> [u8; 64] {
+    let word = |bytes: &[u8]| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+    let mut initial = [0u32; 16];
+    // "expand 32-byte k"
+    initial[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+    for i in 0..8 {
+        initial[4 + i] = word(&key[i * 4..]);
+    }
+    initial[12] = counter;
+    for i in 0..3 {
+        initial[13 + i] = word(&nonce[i * 4..]);
+    }
+
+    let mut state = initial;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut output = [0u8; 64];
+    for i in 0..16 {
+        let value = state[i].wrapping_add(initial[i]);
+        output[i * 4..(i + 1) * 4].copy_from_slice(&value.to_le_bytes());
+    }
+    output
+}
+
+// XORs the keystream into `data`; the same call encrypts and decrypts.
+pub fn apply_keystream(key: &Key, nonce: &[u8; NONCE_LEN], counter: u32, data: &mut [u8]) {
+    for (i, chunk) in data.chunks_mut(64).enumerate() {
+        let keystream = block(key, counter.wrapping_add(i as u32), nonce);
+        for (byte, key_byte) in chunk.iter_mut().zip(keystream) {
+            *byte ^= key_byte;
+        }
+    }
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let nonce = random_bytes::<NONCE_LEN>()?;
+
+    let mut body = Vec::with_capacity(CHECK.len() + plaintext.len());
+    body.extend_from_slice(CHECK);
+    body.extend_from_slice(plaintext);
+    apply_keystream(key, &nonce, 1, &mut body);
+
+    let mut encrypted = Vec::with_capacity(MAGIC.len() + NONCE_LEN + body.len());
+    encrypted.extend_from_slice(MAGIC);
+    encrypted.extend_from_slice(&nonce);
+    encrypted.extend_from_slice(&body);
+    Ok(encrypted)
+}
+
+pub fn decrypt(key: &Key, encrypted: &[u8]) -> Result<Vec<u8>, String> {
+    if !is_encrypted(encrypted) || encrypted.len() < MAGIC.len() + NONCE_LEN + CHECK.len() {
+        return Err(String::from("not an encrypted input"));
+    }
+
+    let (nonce, body) = encrypted[MAGIC.len()..].split_at(NONCE_LEN);
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().unwrap();
+
+    let mut body = body.to_vec();
+    apply_keystream(key, &nonce, 1, &mut body);
+
+    if !body.starts_with(CHECK) {
+        return Err(String::from("wrong key for encrypted input"));
+    }
+
+    Ok(body.split_off(CHECK.len()))
+}
+
+pub fn parse_key(hex: &str) -> Result<Key, String> {
+    let hex = hex.trim();
+    // checked first so slicing by byte below can't split a character
+    if !hex.is_ascii() {
+        return Err(String::from("key must be 64 hex characters"));
+    }
+    if hex.len() != 64 {
+        return Err(format!("key must be 64 hex characters, got {}", hex.len()));
+    }
+
+    let mut key = [0u8; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|_| String::from("key must be 64 hex characters"))?;
+    }
+    Ok(key)
+}
+
+pub fn format_key(key: &Key) -> String {
+    key.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// The key comes from cipher.key (AOC_INPUT_KEY) if set, otherwise from the
+// paths.key file.
+pub fn load_key() -> Result<Key, String> {
+    let config = config::get();
+    if let Some(hex) = &config.input_key {
+        return parse_key(hex).map_err(|error| format!("cipher.key: {}", error));
+    }
+
+    let path = &config.key_file;
+    match fs::read_to_string(path) {
+        Ok(hex) => parse_key(&hex).map_err(|error| format!("{}: {}", path.display(), error)),
+        Err(_) => Err(format!(
+            "No input key found, set AOC_INPUT_KEY or create {}",
+            path.display()
+        )),
+    }
+}
+
+pub fn generate_key() -> Result<Key, String> {
+    random_bytes::<32>()
+}
+
+// From /dev/urandom. Anything derived from the clock could be guessed, so
+// without it there is no key or nonce rather than a weak one.
+fn random_bytes<const N: usize>() -> Result<[u8; N], String> {
+    let mut bytes = [0u8; N];
+    fs::File::open("/dev/urandom")
+        .and_then(|mut urandom| urandom.read_exact(&mut bytes))
+        .map_err(|error| format!("Could not read /dev/urandom: {}", error))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn test_key() -> Key {
+        let mut key = [0u8; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        key
+    }
+
+    #[test]
+    fn test_block_rfc_8439() {
+        // RFC 8439 section 2.3.2
+        let nonce = [0, 0, 0, 0x09, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        assert_eq!(
+            hex(&block(&test_key(), 1, &nonce)),
+            "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+             d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+        );
+    }
+
+    #[test]
+    fn test_apply_keystream_rfc_8439() {
+        // RFC 8439 section 2.4.2
+        let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let mut data = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.".to_vec();
+        apply_keystream(&test_key(), &nonce, 1, &mut data);
+        assert_eq!(
+            hex(&data),
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+             f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+             07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+             5af90bbf74a35be6b40b8eedf2785e42874d"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key = test_key();
+        let encrypted = encrypt(&key, b"L68\nL30\nR48\n").unwrap();
+
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.windows(3).any(|window| window == b"L68"));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"L68\nL30\nR48\n");
+
+        let mut wrong_key = key;
+        wrong_key[0] ^= 1;
+        assert_eq!(
+            decrypt(&wrong_key, &encrypted),
+            Err(String::from("wrong key for encrypted input"))
+        );
+        assert!(decrypt(&key, b"L68\n").is_err(), "plain text is rejected");
+    }
+
+    #[test]
+    fn test_parse_key() {
+        let key = test_key();
+        assert_eq!(parse_key(&format_key(&key)), Ok(key));
+        assert_eq!(parse_key(&format!("{}\n", format_key(&key))), Ok(key));
+        assert!(parse_key("abcd").is_err());
+        assert!(parse_key(&"zz".repeat(32)).is_err());
+        // 64 bytes but not 64 characters
+        assert!(parse_key(&format!("{}é", "0".repeat(62))).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+// Test inputs kept as files rather than inline vectors:
+//
+// fixtures/day_4/example_1.in        the input
+// fixtures/day_4/example_1.expected  "<part> <answer>" per line, # for comments
+//
+// A part missing from .expected isn't checked, for inputs that only make sense
+// for one part. Adding a regression case is dropping in the two files; the
+// fixture_tests! in the day's tests picks them up, and the tui offers them as
+// inputs to run.
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config;
+#[cfg(test)]
+use crate::lint;
+#[cfg(test)]
+use crate::solution::Solution;
+
+// relative to where the binary runs, like the inputs, unless paths.fixtures
+// says otherwise. cargo test runs from the crate root.
+pub const FIXTURES_DIR: &str = "fixtures";
+
+pub fn fixtures_dir(day: u8) -> PathBuf {
+    config::get().fixtures_dir.join(format!("day_{}", day))
+}
+
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Fixture {
+    pub fn lines(&self) -> Vec<String> {
+        self.input.lines().map(String::from).collect()
+    }
+
+    #[cfg(test)]
+    fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            _ => self.part_two.as_deref(),
+        }
+    }
+}
+
+// The input of fixtures/day_N/<name>.in as lines, for tests that need one.
+#[cfg(test)]
+pub fn lines(day: u8, name: &str) -> Vec<String> {
+    let path = fixtures_dir(day).join(format!("{}.in", name));
+    fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Could not read {}: {}", path.display(), error))
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+// Every fixture for the day, sorted by name. An .in without its .expected
+// (or the other way around) is an error rather than a silently skipped case.
+pub fn discover(day: u8) -> Result<Vec<Fixture>, String> {
+    let directory = fixtures_dir(day);
+    let entries = fs::read_dir(&directory)
+        .map_err(|error| format!("Could not read {}: {}", directory.display(), error))?;
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|error| format!("Could not read {}: {}", directory.display(), error))?
+            .path();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        if !matches!(extension, Some("in") | Some("expected")) {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+            && !names.iter().any(|existing| existing == name)
+        {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let read = |extension: &str| {
+                let path = directory.join(format!("{}.{}", name, extension));
+                fs::read_to_string(&path)
+                    .map_err(|error| format!("Could not read {}: {}", path.display(), error))
+            };
+            let input = read("in")?;
+            let (part_one, part_two) = parse_expected(&read("expected")?)
+                .map_err(|error| format!("{}/{}.expected: {}", directory.display(), name, error))?;
+
+            Ok(Fixture {
+                name,
+                input,
+                part_one,
+                part_two,
+            })
+        })
+        .collect()
+}
+
+type Expected = (Option<String>, Option<String>);
+
+fn parse_expected(text: &str) -> Result<Expected, String> {
+    let mut expected = (None, None);
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (part, answer) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| format!("line {}: expected \"<part> <answer>\"", index + 1))?;
+        let slot = match part {
+            "1" => &mut expected.0,
+            "2" => &mut expected.1,
+            _ => return Err(format!("line {}: invalid part {}", index + 1, part)),
+        };
+        if slot.is_some() {
+            return Err(format!("line {}: part {} is given twice", index + 1, part));
+        }
+        *slot = Some(answer.trim().to_string());
+    }
+
+    Ok(expected)
+}
+
+// Runs every fixture through the day's lint and both parts (and its
+// streaming and byte solvers when it has them), then fails once with every
+// mismatch rather than stopping at the first.
+#[cfg(test)]
+#[track_caller]
+pub fn run(solution: &Solution) {
+    let fixtures = discover(solution.day).unwrap_or_else(|error| panic!("{}", error));
+    assert!(
+        !fixtures.is_empty(),
+        "No fixtures in {}",
+        fixtures_dir(solution.day).display()
+    );
+
+    let mut failures = Vec::new();
+    for fixture in &fixtures {
+        let lines = fixture.lines();
+        let problems = lint::check(solution, &lines);
+        if !problems.is_empty() {
+            for problem in &problems {
+                failures.push(problem.render(&format!("{}.in", fixture.name), &lines));
+            }
+            continue;
+        }
+
+        for part in [1, 2] {
+            let Some(expected) = fixture.expected(part) else {
+                continue;
+            };
+
+            let mut answers = vec![("part", solution.part(part)(&lines))];
+            if let Some(stream) = solution.stream {
+                let answer = stream(&mut fixture.input.as_bytes(), part)
+                    .unwrap_or_else(|error| error.to_string());
+                answers.push(("stream", answer));
+            }
+            if let Some(solve_bytes) = solution.solve_bytes {
+                answers.push(("bytes", solve_bytes(fixture.input.as_bytes(), part)));
+            }
+
+            for (solver, answer) in answers {
+                if answer != expected {
+                    failures.push(format!(
+                        "{}: {} {} gave {}, expected {}",
+                        fixture.name, solver, part, answer, expected
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Day {} failed its fixtures ({} checked):\n{}",
+        solution.day,
+        fixtures.len(),
+        failures.join("\n")
+    );
+}
+
+// Adds a test running every fixture in fixtures/day_N for the day's SOLUTION.
+#[cfg(test)]
+#[allow(unused_macros)]
+macro_rules! fixture_tests {
+    ($solution:expr) => {
+        #[test]
+        fn test_fixtures() {
+            $crate::util::fixtures::run(&$solution);
+        }
+    };
+}
+
+// unused with no day compiled in, e.g. --no-default-features
+#[cfg(test)]
+#[allow(unused_imports)]
+pub(crate) use fixture_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        assert_eq!(
+            parse_expected("# the worked example\n1 3\n2 6\n"),
+            Ok((Some(String::from("3")), Some(String::from("6"))))
+        );
+        assert_eq!(parse_expected("2 14"), Ok((None, Some(String::from("14")))));
+        assert_eq!(
+            parse_expected("1 3\n1 4"),
+            Err(String::from("line 2: part 1 is given twice"))
+        );
+        assert_eq!(
+            parse_expected("3 1"),
+            Err(String::from("line 1: invalid part 3"))
+        );
+        assert_eq!(
+            parse_expected("1"),
+            Err(String::from("line 1: expected \"<part> <answer>\""))
+        );
+    }
+}
@@ -0,0 +1,107 @@
+// Deterministic input generators for benchmarks and stress tests. The random
+// numbers come from a small xorshift generator so runs are repeatable without
+// pulling in a dependency.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift gets stuck on 0
+        Rng { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    // A number in start..=end.
+    pub fn range(&mut self, start: i64, end: i64) -> i64 {
+        let span = (end - start) as u64 + 1;
+        start + (self.next_u64() % span) as i64
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    // Fisher-Yates, so every order is as likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            let other = self.range(0, index as i64) as usize;
+            items.swap(index, other);
+        }
+    }
+}
+
+// `count` inclusive ranges with starts below `max` and lengths up to `max_length`.
+pub fn ranges(rng: &mut Rng, count: usize, max: i64, max_length: i64) -> Vec<(i64, i64)> {
+    (0..count)
+        .map(|_| {
+            let start = rng.range(1, max);
+            (start, start + rng.range(0, max_length))
+        })
+        .collect()
+}
+
+// A width x height grid of `filled` and `empty` cells, `density` percent filled.
+pub fn grid(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    density: u64,
+    filled: char,
+    empty: char,
+) -> Vec<String> {
+    (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| if rng.chance(density) { filled } else { empty })
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut first = Rng::new(42);
+        let mut second = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            let value = rng.range(-3, 3);
+            assert!((-3..=3).contains(&value), "{} out of range", value);
+        }
+    }
+
+    #[test]
+    fn test_grid_shape() {
+        let grid = grid(&mut Rng::new(7), 12, 5, 50, '@', '.');
+        assert_eq!(grid.len(), 5);
+        assert!(grid.iter().all(|row| row.len() == 12));
+        assert!(
+            grid.iter()
+                .all(|row| row.chars().all(|cell| cell == '@' || cell == '.'))
+        );
+    }
+
+    #[test]
+    fn test_shuffle_keeps_every_item() {
+        let mut items = (0..50).collect::<Vec<i32>>();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<i32>>());
+
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<i32>>());
+    }
+}
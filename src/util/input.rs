@@ -0,0 +1,204 @@
+use std::fs;
+use std::io::{self, BufRead};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::util::cipher;
+#[cfg(all(feature = "mmap", unix))]
+use crate::util::mmap;
+
+// the defaults, aoc.toml can move either
+pub const INPUT_DIR: &str = "inputs";
+pub const ANSWERS_DIR: &str = "answers";
+
+// inputs/2025/day_1.txt
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    config::get()
+        .input_dir
+        .join(year.to_string())
+        .join(format!("day_{}.txt", day))
+}
+
+// inputs/2025/day_1.txt.enc, written by the encrypt-inputs command
+pub fn encrypted_input_path(year: u16, day: u8) -> PathBuf {
+    let mut path = input_path(year, day).into_os_string();
+    path.push(".enc");
+    PathBuf::from(path)
+}
+
+// Reads the plain input if there is one, otherwise decrypts the encrypted
+// copy in memory so the plain text never touches the disk.
+pub fn read_input(year: u16, day: u8) -> io::Result<String> {
+    let plain_path = input_path(year, day);
+    let encrypted_path = encrypted_input_path(year, day);
+
+    if plain_path.exists() || !encrypted_path.exists() {
+        return fs::read_to_string(plain_path);
+    }
+
+    let plaintext = decrypt_input(&encrypted_path)?;
+    String::from_utf8(plaintext).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", encrypted_path.display(), error),
+        )
+    })
+}
+
+fn decrypt_input(encrypted_path: &Path) -> io::Result<Vec<u8>> {
+    let encrypted = fs::read(encrypted_path)?;
+    let invalid_data = |error: String| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", encrypted_path.display(), error),
+        )
+    };
+
+    let key = cipher::load_key().map_err(invalid_data)?;
+    cipher::decrypt(&key, &encrypted).map_err(invalid_data)
+}
+
+// An input as raw bytes for the byte solvers: memory-mapped when built with
+// the mmap feature, read into memory otherwise.
+pub enum InputBytes {
+    #[cfg(all(feature = "mmap", unix))]
+    Mapped(mmap::Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Deref for InputBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(all(feature = "mmap", unix))]
+            InputBytes::Mapped(mapped) => mapped,
+            InputBytes::Owned(bytes) => bytes,
+        }
+    }
+}
+
+// Same lookup as read_input. An encrypted input has to be decrypted into
+// memory, so only plain inputs are mapped.
+pub fn read_bytes(year: u16, day: u8) -> io::Result<InputBytes> {
+    let plain_path = input_path(year, day);
+    let encrypted_path = encrypted_input_path(year, day);
+
+    if plain_path.exists() || !encrypted_path.exists() {
+        return read_bytes_from(&plain_path);
+    }
+
+    decrypt_input(&encrypted_path).map(InputBytes::Owned)
+}
+
+pub fn read_bytes_from(path: &Path) -> io::Result<InputBytes> {
+    #[cfg(all(feature = "mmap", unix))]
+    return mmap::Mmap::open(path).map(InputBytes::Mapped);
+
+    #[cfg(not(all(feature = "mmap", unix)))]
+    fs::read(path).map(InputBytes::Owned)
+}
+
+pub fn read_lines(year: u16, day: u8) -> io::Result<Vec<String>> {
+    let contents = read_input(year, day)?;
+    Ok(contents.lines().map(String::from).collect())
+}
+
+// Calls `on_line` with each line number (from 1) and line, reusing one
+// buffer so memory stays at the longest line however big the input is.
+// Stops early once `on_line` returns false.
+pub fn stream_lines(
+    reader: &mut dyn BufRead,
+    mut on_line: impl FnMut(usize, &str) -> io::Result<bool>,
+) -> io::Result<()> {
+    let mut buffer = String::new();
+    let mut number = 0;
+
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        let line = buffer.trim_end_matches('\n').trim_end_matches('\r');
+        if !on_line(number, line)? {
+            return Ok(());
+        }
+    }
+}
+
+// The error a streaming solver gives up with on a line it can't parse.
+pub fn invalid_line(number: usize, message: impl std::fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", number, message),
+    )
+}
+
+// answers/2025.txt, one "<day> <part> <answer>" per line
+pub fn answers_path(year: u16) -> PathBuf {
+    config::get().answers_dir.join(format!("{}.txt", year))
+}
+
+pub fn read_answer(year: u16, day: u8, part: u8) -> Option<String> {
+    let contents = fs::read_to_string(answers_path(year)).ok()?;
+
+    contents
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let mut fields = line.split_whitespace();
+            let line_day = fields.next()?.parse::<u8>().ok()?;
+            let line_part = fields.next()?.parse::<u8>().ok()?;
+            let answer = fields.next()?;
+
+            if line_day == day && line_part == part {
+                Some(answer.to_string())
+            } else {
+                None
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_bytes_from() {
+        let path = std::env::temp_dir().join(format!("aoc_bytes_{}.txt", std::process::id()));
+        fs::write(&path, "987654321111111\n811111111111119\n").unwrap();
+        let bytes = read_bytes_from(&path).unwrap();
+        assert_eq!(&bytes[..], b"987654321111111\n811111111111119\n");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_stream_lines() {
+        let mut lines = Vec::new();
+        stream_lines(&mut "a\r\nb\n\nc".as_bytes(), |number, line| {
+            lines.push((number, line.to_string()));
+            Ok(true)
+        })
+        .unwrap();
+        assert_eq!(
+            lines,
+            vec![
+                (1, String::from("a")),
+                (2, String::from("b")),
+                (3, String::new()),
+                (4, String::from("c"))
+            ]
+        );
+
+        let mut count = 0;
+        stream_lines(&mut "a\nb\nc\n".as_bytes(), |number, _| {
+            count += 1;
+            Ok(number < 2)
+        })
+        .unwrap();
+        assert_eq!(count, 2, "stops once on_line returns false");
+    }
+}
@@ -0,0 +1,485 @@
+// JSON for the files other tools hand us, e.g. an adventofcode.com
+// leaderboard export, and the bodies serve writes. The whole grammar is
+// read, with every number as an f64 and objects keeping their keys in order.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    // How the value reads in an error message, e.g. "a string".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Boolean(_) => "a boolean",
+            Value::Number(_) => "a number",
+            Value::String(_) => "a string",
+            Value::Array(_) => "an array",
+            Value::Object(_) => "an object",
+        }
+    }
+
+    // The value under `key` when this is an object that has it.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_object()?
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Value)]> {
+        match self {
+            Value::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    // Whole numbers from 0 up, which is every id, count and timestamp.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Value::Number(number)
+                if number >= 0.0 && number.fract() == 0.0 && number < u64::MAX as f64 =>
+            {
+                Some(number as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+// Where the text stopped being JSON, `line` and `column` counting from 1.
+#[derive(Debug, PartialEq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+// deep enough for anything real, shallow enough not to run out of stack
+const MAX_DEPTH: usize = 128;
+
+pub fn parse(text: &str) -> Result<Value, Error> {
+    let mut parser = Parser {
+        text,
+        position: 0,
+        depth: 0,
+    };
+
+    let value = parser.value().map_err(|message| parser.error(message))?;
+    parser.skip_whitespace();
+    if parser.position < text.len() {
+        return Err(parser.error(String::from("unexpected text after the value")));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: String) -> Error {
+        let before = &self.text[..self.position];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Error {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    // What's at the current position, for "expected X, found Y".
+    fn found(&self) -> String {
+        match self.text[self.position..].chars().next() {
+            Some(character) => format!("'{}'", character),
+            None => String::from("the end of the text"),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(format!(
+                "expected '{}', found {}",
+                byte as char,
+                self.found()
+            ));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.nested(Parser::object),
+            Some(b'[') => self.nested(Parser::array),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't') => self.literal("true", Value::Boolean(true)),
+            Some(b'f') => self.literal("false", Value::Boolean(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            _ => Err(format!("expected a value, found {}", self.found())),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value, String>) -> Result<Value, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(format!("nested deeper than {} levels", MAX_DEPTH));
+        }
+        self.depth += 1;
+        let value = parse(self)?;
+        self.depth -= 1;
+        Ok(value)
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.position += 1;
+        let mut entries = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Value::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(format!("expected a key, found {}", self.found()));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            entries.push((key, self.value()?));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Value::Object(entries));
+                }
+                _ => return Err(format!("expected ',' or '}}', found {}", self.found())),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.position += 1;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(format!("expected ',' or ']', found {}", self.found())),
+            }
+        }
+    }
+
+    // The string starting at the opening quote, unescaped.
+    fn string(&mut self) -> Result<String, String> {
+        self.position += 1;
+        let mut value = String::new();
+
+        loop {
+            let Some(character) = self.text[self.position..].chars().next() else {
+                return Err(String::from("expected '\"' to close the string"));
+            };
+            match character {
+                '"' => {
+                    self.position += 1;
+                    return Ok(value);
+                }
+                '\\' => {
+                    self.position += 1;
+                    value.push(self.escape()?);
+                }
+                character if (character as u32) < 0x20 => {
+                    return Err(format!(
+                        "control character U+{:04X} in a string, it needs escaping",
+                        character as u32
+                    ));
+                }
+                character => {
+                    self.position += character.len_utf8();
+                    value.push(character);
+                }
+            }
+        }
+    }
+
+    // The character after a backslash, with \u escapes joined into
+    // surrogate pairs where they come in two.
+    fn escape(&mut self) -> Result<char, String> {
+        let Some(escaped) = self.peek() else {
+            return Err(String::from("expected '\"' to close the string"));
+        };
+        self.position += 1;
+
+        let character = match escaped {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{8}',
+            b'f' => '\u{c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let high = self.hex()?;
+                if !(0xd800..0xdc00).contains(&high) {
+                    return char::from_u32(high)
+                        .ok_or_else(|| format!("\\u{:04x} is half of a surrogate pair", high));
+                }
+                if !self.text[self.position..].starts_with("\\u") {
+                    return Err(format!("\\u{:04x} is half of a surrogate pair", high));
+                }
+                self.position += 2;
+                let low = self.hex()?;
+                if !(0xdc00..0xe000).contains(&low) {
+                    return Err(format!("\\u{:04x} is half of a surrogate pair", high));
+                }
+                char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+                    .expect("a surrogate pair is always a character")
+            }
+            _ => {
+                self.position -= 1;
+                return Err(format!(
+                    "unsupported escape \\{}",
+                    self.found().trim_matches('\'')
+                ));
+            }
+        };
+        Ok(character)
+    }
+
+    fn hex(&mut self) -> Result<u32, String> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .unwrap_or("");
+        if digits.len() != 4 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(String::from("expected four hex digits after \\u"));
+        }
+        self.position += 4;
+        Ok(u32::from_str_radix(digits, 16).expect("checked to be hex digits"))
+    }
+
+    // -?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            let from = parser.position;
+            while matches!(parser.peek(), Some(b'0'..=b'9')) {
+                parser.position += 1;
+            }
+            parser.position - from
+        };
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => {
+                digits(self);
+            }
+            _ => return Err(format!("expected a digit, found {}", self.found())),
+        }
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            if digits(self) == 0 {
+                return Err(format!("expected a digit, found {}", self.found()));
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            if digits(self) == 0 {
+                return Err(format!("expected a digit, found {}", self.found()));
+            }
+        }
+
+        self.text[start..self.position]
+            .parse()
+            .map(Value::Number)
+            .map_err(|error| format!("invalid number: {}", error))
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, String> {
+        if !self.text[self.position..].starts_with(word) {
+            return Err(format!("expected a value, found {}", self.found()));
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+}
+
+// `text` as a JSON string, quotes included.
+pub fn string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str) -> (usize, usize, String) {
+        let error = parse(text).unwrap_err();
+        (error.line, error.column, error.message)
+    }
+
+    #[test]
+    fn test_parse() {
+        let value = parse(
+            r#"{
+  "event": "2025",
+  "members": {"1": {"name": null, "stars": 10, "ok": true}},
+  "scores": [1, -2.5, 3e2, 0]
+}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            value.get("event"),
+            Some(&Value::String(String::from("2025")))
+        );
+        let member = value.get("members").and_then(|members| members.get("1"));
+        assert_eq!(
+            member.and_then(|member| member.get("name")),
+            Some(&Value::Null)
+        );
+        assert_eq!(
+            member
+                .and_then(|member| member.get("stars"))
+                .and_then(Value::as_u64),
+            Some(10)
+        );
+        assert_eq!(
+            value.get("scores"),
+            Some(&Value::Array(vec![
+                Value::Number(1.0),
+                Value::Number(-2.5),
+                Value::Number(300.0),
+                Value::Number(0.0)
+            ]))
+        );
+        assert_eq!(value.get("missing"), None);
+        assert_eq!(parse(" [] ").unwrap(), Value::Array(Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_strings() {
+        assert_eq!(
+            parse(r#""a \"b\" \\ \/ \n\t\u00e9 \ud83c\udf84""#).unwrap(),
+            Value::String(String::from("a \"b\" \\ / \n\té 🎄"))
+        );
+        assert_eq!(
+            parse("\"ünï\"").unwrap(),
+            Value::String(String::from("ünï"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            error("{\n  \"a\": 1,\n  \"b\" 2\n}"),
+            (3, 7, String::from("expected ':', found '2'"))
+        );
+        assert_eq!(
+            error("[1, 2"),
+            (
+                1,
+                6,
+                String::from("expected ',' or ']', found the end of the text")
+            )
+        );
+        assert_eq!(
+            error("{\"a\": tru}"),
+            (1, 7, String::from("expected a value, found 't'"))
+        );
+        assert_eq!(
+            error("\"é\\x\""),
+            (1, 4, String::from("unsupported escape \\x"))
+        );
+        assert_eq!(
+            error("{} {}"),
+            (1, 4, String::from("unexpected text after the value"))
+        );
+        assert!(parse("01").is_err(), "leading zero");
+        assert!(parse("1.").is_err(), "no digits after the point");
+        assert!(parse("\"\\ud83c\"").is_err(), "half a surrogate pair");
+        assert!(
+            parse("\"tab\there\"").is_err(),
+            "unescaped control character"
+        );
+        assert!(parse(&"[".repeat(1000)).is_err(), "too deep");
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("92"), "\"92\"");
+        assert_eq!(string("a \"b\"\n\\"), "\"a \\\"b\\\"\\n\\\\\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+        assert_eq!(
+            parse(&string("round \"trip\"\n\u{1}")).unwrap(),
+            Value::String(String::from("round \"trip\"\n\u{1}"))
+        );
+    }
+}
@@ -0,0 +1,115 @@
+// A read-only memory map of a whole file, so a multi-gigabyte input can be
+// handed to a solver as one `&[u8]` without reading it into the heap. Talks
+// to mmap(2) directly rather than pulling in libc for two functions.
+
+use std::ffi::c_void;
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::os::fd::AsRawFd;
+use std::path::Path;
+use std::ptr;
+use std::slice;
+
+const PROT_READ: i32 = 1;
+const MAP_PRIVATE: i32 = 2;
+const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+
+// off_t as the plain mmap symbol takes it, the width of a pointer. A fixed
+// i64 would push the arguments out of line on 32-bit targets.
+#[cfg(target_pointer_width = "64")]
+type OffT = i64;
+#[cfg(target_pointer_width = "32")]
+type OffT = i32;
+
+unsafe extern "C" {
+    fn mmap(
+        addr: *mut c_void,
+        length: usize,
+        prot: i32,
+        flags: i32,
+        fd: i32,
+        offset: OffT,
+    ) -> *mut c_void;
+    fn munmap(addr: *mut c_void, length: usize) -> i32;
+}
+
+pub struct Mmap {
+    ptr: *mut c_void,
+    len: usize,
+}
+
+impl Mmap {
+    pub fn open(path: &Path) -> io::Result<Mmap> {
+        let file = File::open(path)?;
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file too large to map"))?;
+
+        // mmap refuses a zero length, and there's nothing to map anyway
+        if len == 0 {
+            return Ok(Mmap {
+                ptr: ptr::null_mut(),
+                len,
+            });
+        }
+
+        // the mapping outlives the file descriptor, closing it doesn't unmap
+        let ptr = unsafe {
+            mmap(
+                ptr::null_mut(),
+                len,
+                PROT_READ,
+                MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Mmap { ptr, len })
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        if self.ptr.is_null() {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe {
+                munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_map_file() {
+        let path = env::temp_dir().join(format!("aoc_mmap_{}.txt", std::process::id()));
+        fs::write(&path, "..@@.\n@@@.@\n").unwrap();
+        let mapped = Mmap::open(&path).unwrap();
+        assert_eq!(&mapped[..], b"..@@.\n@@@.@\n");
+
+        fs::write(&path, "").unwrap();
+        assert!(Mmap::open(&path).unwrap().is_empty());
+
+        fs::remove_file(&path).unwrap();
+        assert!(Mmap::open(&path).is_err());
+    }
+}
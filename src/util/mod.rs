@@ -0,0 +1,16 @@
+// Helpers shared by every year's solutions.
+pub mod bytes;
+pub mod cipher;
+pub mod fixtures;
+pub mod generate;
+pub mod input;
+pub mod json;
+#[cfg(all(feature = "mmap", unix))]
+pub mod mmap;
+pub mod narrate;
+#[cfg(test)]
+pub mod snapshot;
+pub mod toml;
+
+// grids and ranges live in the no_std core crate
+pub use aoc_core::{grid, ranges};
@@ -0,0 +1,86 @@
+// Wording helpers for explain mode, so the narration reads like the puzzle
+// text ("it points at 0 once", "has two invalid IDs, 11 and 22").
+
+use crate::solution::Explain;
+
+// "one" through "ten", digits after that
+pub fn number(n: usize) -> String {
+    const WORDS: [&str; 11] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    WORDS
+        .get(n)
+        .map(|word| word.to_string())
+        .unwrap_or_else(|| n.to_string())
+}
+
+// "once", "twice", "three times"
+pub fn times(n: usize) -> String {
+    match n {
+        1 => String::from("once"),
+        2 => String::from("twice"),
+        _ => format!("{} times", number(n)),
+    }
+}
+
+// "one invalid ID", "two invalid IDs"
+pub fn count(n: usize, singular: &str, plural: &str) -> String {
+    if n == 1 {
+        format!("{} {}", number(n), singular)
+    } else {
+        format!("{} {}", number(n), plural)
+    }
+}
+
+// "99", "11 and 22", "3, 4, and 5"
+pub fn list<T: ToString>(items: &[T]) -> String {
+    let items = items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>();
+    match items.as_slice() {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{} and {}", first, second),
+        [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+    }
+}
+
+// Runs a day's explain function and gathers the narration, one step per
+// line, alongside the answer.
+pub fn collect(explain: Explain, input: &[String], part: u8) -> (String, String) {
+    let mut narration = String::new();
+    let answer = explain(input, part, &mut |line| {
+        narration.push_str(&line);
+        narration.push('\n');
+    });
+    (answer, narration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_times() {
+        assert_eq!(times(1), "once");
+        assert_eq!(times(2), "twice");
+        assert_eq!(times(3), "three times");
+        assert_eq!(times(12), "12 times");
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count(1, "invalid ID", "invalid IDs"), "one invalid ID");
+        assert_eq!(count(2, "invalid ID", "invalid IDs"), "two invalid IDs");
+        assert_eq!(count(0, "invalid ID", "invalid IDs"), "zero invalid IDs");
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list::<i64>(&[]), "");
+        assert_eq!(list(&[99]), "99");
+        assert_eq!(list(&[11, 22]), "11 and 22");
+        assert_eq!(list(&[3, 4, 5]), "3, 4, and 5");
+    }
+}
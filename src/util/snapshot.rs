@@ -0,0 +1,224 @@
+// Snapshot testing for textual output such as rendered grids.
+//
+// Expected output lives in snapshots/<name>.snap at the crate root. A mismatch
+// panics with a unified diff of expected against actual; running the tests with
+// UPDATE_SNAPSHOTS=1 writes the actual output as the new snapshot instead.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const UPDATE_ENV_VAR: &str = "UPDATE_SNAPSHOTS";
+
+// lines of unchanged context printed around each change
+const CONTEXT: usize = 3;
+
+pub fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{}.snap", name))
+}
+
+fn update_mode() -> bool {
+    std::env::var(UPDATE_ENV_VAR).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+#[track_caller]
+pub fn assert_snapshot(name: &str, actual: &str) {
+    assert_matches_file(&snapshot_path(name), actual);
+}
+
+// The same check against any file in the repository, for generated files
+// that live outside snapshots/.
+#[track_caller]
+pub fn assert_matches_file(path: &Path, actual: &str) {
+    if update_mode() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, actual).unwrap();
+        return;
+    }
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(_) => panic!(
+            "Missing snapshot {}, run with {}=1 to create it. Actual output:\n{}",
+            path.display(),
+            UPDATE_ENV_VAR,
+            actual
+        ),
+    };
+
+    if expected != actual {
+        panic!(
+            "Snapshot {} does not match, run with {}=1 to update it.\n{}",
+            path.display(),
+            UPDATE_ENV_VAR,
+            unified_diff(&expected, actual, "expected", "actual")
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    Same,
+    Removed,
+    Added,
+}
+
+// Line diff via the longest common subsequence, which is plenty for the size
+// of output we snapshot.
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<(Edit, &'a str)> {
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            edits.push((Edit::Same, expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            // removals first so a changed line reads as - then +
+            edits.push((Edit::Removed, expected[i]));
+            i += 1;
+        } else {
+            edits.push((Edit::Added, actual[j]));
+            j += 1;
+        }
+    }
+
+    edits
+}
+
+pub fn unified_diff(
+    expected: &str,
+    actual: &str,
+    expected_name: &str,
+    actual_name: &str,
+) -> String {
+    let expected_lines = expected.lines().collect::<Vec<&str>>();
+    let actual_lines = actual.lines().collect::<Vec<&str>>();
+    let edits = diff_lines(&expected_lines, &actual_lines);
+
+    let mut output = format!("--- {}\n+++ {}\n", expected_name, actual_name);
+
+    // group the changes into hunks, merging ones whose context would overlap
+    let changed = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, (edit, _))| *edit != Edit::Same)
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for index in changed {
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + CONTEXT + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        // line numbers of the hunk start in each file
+        let expected_start = edits[..start]
+            .iter()
+            .filter(|(edit, _)| *edit != Edit::Added)
+            .count();
+        let actual_start = edits[..start]
+            .iter()
+            .filter(|(edit, _)| *edit != Edit::Removed)
+            .count();
+        let hunk = &edits[start..end];
+        let expected_count = hunk.iter().filter(|(edit, _)| *edit != Edit::Added).count();
+        let actual_count = hunk
+            .iter()
+            .filter(|(edit, _)| *edit != Edit::Removed)
+            .count();
+
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            expected_start + 1,
+            expected_count,
+            actual_start + 1,
+            actual_count
+        ));
+
+        for (edit, line) in hunk {
+            let marker = match edit {
+                Edit::Same => ' ',
+                Edit::Removed => '-',
+                Edit::Added => '+',
+            };
+            output.push_str(&format!("{}{}\n", marker, line));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni\n";
+        let actual = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\n";
+
+        assert_eq!(
+            unified_diff(expected, actual, "expected", "actual"),
+            "--- expected\n+++ actual\n\
+             @@ -2,8 +2,9 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n i\n+j\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_separate_hunks() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let actual = "one\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\ntwelve\n";
+        let diff = unified_diff(expected, actual, "expected", "actual");
+
+        assert!(
+            diff.contains("@@ -1,4 +1,4 @@\n-1\n+one\n 2\n 3\n 4\n"),
+            "{}",
+            diff
+        );
+        assert!(
+            diff.contains("@@ -9,4 +9,4 @@\n 9\n 10\n 11\n-12\n+twelve\n"),
+            "{}",
+            diff
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_identical() {
+        assert_eq!(unified_diff("a\n", "a\n", "x", "y"), "--- x\n+++ y\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match")]
+    fn test_assert_snapshot_mismatch() {
+        if update_mode() {
+            panic!("does not match, skipped in update mode");
+        }
+        assert_snapshot("util/snapshot_example", "something else\n");
+    }
+
+    #[test]
+    fn test_assert_snapshot() {
+        assert_snapshot("util/snapshot_example", "line one\nline two\n");
+    }
+}
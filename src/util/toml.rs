@@ -0,0 +1,267 @@
+// The part of TOML the config file needs: comments, [tables] and key = value
+// pairs with string, integer, float and boolean values. Arrays, inline tables
+// and multi-line strings are rejected rather than half-supported.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+impl Value {
+    // How the value reads in an error message, e.g. "a string".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::Boolean(_) => "a boolean",
+        }
+    }
+}
+
+// One key = value line, the key joined onto its table with dots, e.g.
+// `threshold` under `[2025.day_4]` is 2025.day_4.threshold.
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub line: usize,
+    pub key: String,
+    pub value: Value,
+}
+
+// Every entry in the order it appears, or the number of the first line that
+// didn't parse and why.
+pub fn parse(text: &str) -> Result<Vec<Entry>, (usize, String)> {
+    let mut table = String::new();
+    let mut entries = Vec::<Entry>::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let error = |message: String| (number, message);
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let (header, rest) = header
+                .split_once(']')
+                .ok_or_else(|| error(String::from("expected ] to close the table")))?;
+            if header.starts_with('[') {
+                return Err(error(String::from("arrays of tables aren't supported")));
+            }
+            check_trailing(rest).map_err(error)?;
+            table = parse_key(header).map_err(error)?;
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(format!("expected key = value, found '{}'", line)))?;
+        let key = parse_key(key).map_err(error)?;
+        let key = if table.is_empty() {
+            key
+        } else {
+            format!("{}.{}", table, key)
+        };
+        let value = parse_value(value.trim()).map_err(error)?;
+
+        if entries.iter().any(|entry| entry.key == key) {
+            return Err(error(format!("{} is set twice", key)));
+        }
+        entries.push(Entry {
+            line: number,
+            key,
+            value,
+        });
+    }
+
+    Ok(entries)
+}
+
+// A bare key, possibly dotted. Whitespace around the dots is allowed.
+fn parse_key(key: &str) -> Result<String, String> {
+    let parts = key.split('.').map(str::trim).collect::<Vec<&str>>();
+    for part in &parts {
+        if part.is_empty()
+            || !part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!("invalid key '{}'", key.trim()));
+        }
+    }
+    Ok(parts.join("."))
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if let Some(rest) = text.strip_prefix('"') {
+        let (value, rest) = parse_basic_string(rest)?;
+        check_trailing(rest)?;
+        return Ok(Value::String(value));
+    }
+    if let Some(rest) = text.strip_prefix('\'') {
+        let (value, rest) = rest
+            .split_once('\'')
+            .ok_or_else(|| String::from("expected ' to close the string"))?;
+        check_trailing(rest)?;
+        return Ok(Value::String(value.to_string()));
+    }
+
+    // everything else runs up to a comment
+    let text = text.split_once('#').map_or(text, |(value, _)| value).trim();
+    match text {
+        "" => Err(String::from("expected a value")),
+        "true" => Ok(Value::Boolean(true)),
+        "false" => Ok(Value::Boolean(false)),
+        _ if text.starts_with('[') || text.starts_with('{') => Err(format!(
+            "arrays and inline tables aren't supported, found '{}'",
+            text
+        )),
+        _ => {
+            let number = text.replace('_', "");
+            if let Ok(integer) = number.parse::<i64>() {
+                Ok(Value::Integer(integer))
+            } else if let Ok(float) = number.parse::<f64>() {
+                Ok(Value::Float(float))
+            } else {
+                Err(format!(
+                    "expected a string, number or boolean, found '{}'",
+                    text
+                ))
+            }
+        }
+    }
+}
+
+// The string after an opening ", and whatever follows the closing one.
+fn parse_basic_string(text: &str) -> Result<(String, &str), String> {
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &text[index + 1..])),
+            '\\' => match chars.next().map(|(_, escaped)| escaped) {
+                Some('"') => value.push('"'),
+                Some('\\') => value.push('\\'),
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some(other) => return Err(format!("unsupported escape \\{}", other)),
+                None => break,
+            },
+            _ => value.push(c),
+        }
+    }
+
+    Err(String::from("expected \" to close the string"))
+}
+
+// Only a comment may follow a value or a table header.
+fn check_trailing(rest: &str) -> Result<(), String> {
+    let rest = rest.trim();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok(())
+    } else {
+        Err(format!("unexpected '{}' after the value", rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(text: &str) -> Vec<(String, Value)> {
+        parse(text)
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.key, entry.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = r#"
+# paths are relative to where the binary runs
+top = true
+
+[paths]
+inputs = "my inputs" # trailing comment
+session = '~\.session'
+
+[2025.day_4]
+threshold = 5
+scale = 1.5
+big = 1_000
+"#;
+
+        assert_eq!(
+            values(text),
+            vec![
+                (String::from("top"), Value::Boolean(true)),
+                (
+                    String::from("paths.inputs"),
+                    Value::String(String::from("my inputs"))
+                ),
+                (
+                    String::from("paths.session"),
+                    Value::String(String::from("~\\.session"))
+                ),
+                (String::from("2025.day_4.threshold"), Value::Integer(5)),
+                (String::from("2025.day_4.scale"), Value::Float(1.5)),
+                (String::from("2025.day_4.big"), Value::Integer(1000)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_strings() {
+        assert_eq!(
+            values(r#"a = "say \"hi\"\tnow # not a comment""#),
+            vec![(
+                String::from("a"),
+                Value::String(String::from("say \"hi\"\tnow # not a comment"))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("[paths"),
+            Err((1, String::from("expected ] to close the table")))
+        );
+        assert_eq!(
+            parse("\n\ninputs"),
+            Err((3, String::from("expected key = value, found 'inputs'")))
+        );
+        assert_eq!(
+            parse("a = \"open"),
+            Err((1, String::from("expected \" to close the string")))
+        );
+        assert_eq!(
+            parse("a = [1, 2]"),
+            Err((
+                1,
+                String::from("arrays and inline tables aren't supported, found '[1, 2]'")
+            ))
+        );
+        assert_eq!(
+            parse("a = 1\na = 2"),
+            Err((2, String::from("a is set twice")))
+        );
+        assert_eq!(
+            parse("a b = 1"),
+            Err((1, String::from("invalid key 'a b'")))
+        );
+        assert_eq!(
+            parse("a = \"x\" y"),
+            Err((1, String::from("unexpected 'y' after the value")))
+        );
+        assert!(parse("a = nope").is_err());
+        assert!(parse("[[days]]").is_err());
+    }
+}
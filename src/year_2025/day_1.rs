@@ -0,0 +1,806 @@
+// -- Day 1: Secret Entrance ---
+
+// You arrive at the secret entrance to the North Pole base ready to start decorating.
+// Unfortunately, the password seems to have been changed, so you can't get in.
+// A document taped to the wall helpfully explains:
+
+// "Due to new security protocols, the password is locked in the safe below. Please see
+// the attached document for the new combination."
+
+// The safe has a dial with only an arrow on it; around the dial are the numbers 0 through 99 in order.
+// As you turn the dial, it makes a small click noise as it reaches each number.
+
+// The attached document (your puzzle input) contains a sequence of rotations, one per line,
+// which tell you how to open the safe. A rotation starts with an L or R which indicates whether
+// the rotation should be to the left (toward lower numbers) or to the right (toward higher numbers).
+// Then, the rotation has a distance value which indicates how many clicks the dial should be rotated
+//in that direction.
+
+// So, if the dial were pointing at 11, a rotation of R8 would cause the dial to point at 19.
+// After that, a rotation of L19 would cause it to point at 0.
+// Because the dial is a circle, turning the dial left from 0 one click makes it point at 99.
+// Similarly, turning the dial right from 99 one click makes it point at 0.
+
+// So, if the dial were pointing at 5, a rotation of L10 would cause it to point at 95.
+//After that, a rotation of R5 could cause it to point at 0.
+
+// The dial starts by pointing at 50.
+
+// You could follow the instructions, but your recent required official North Pole secret entrance security training
+// seminar taught you that the safe is actually a decoy. The actual password is the number of times the dial is left
+// pointing at 0 after any rotation in the sequence.
+
+// For example, suppose the attached document contained the following rotations:
+
+// L68
+// L30
+// R48
+// L5
+// R60
+// L55
+// L1
+// L99
+// R14
+// L82
+// Following these rotations would cause the dial to move as follows:
+
+// The dial starts by pointing at 50.
+// The dial is rotated L68 to point at 82.
+// The dial is rotated L30 to point at 52.
+// The dial is rotated R48 to point at 0.
+// The dial is rotated L5 to point at 95.
+// The dial is rotated R60 to point at 55.
+// The dial is rotated L55 to point at 0.
+// The dial is rotated L1 to point at 99.
+// The dial is rotated L99 to point at 0.
+// The dial is rotated R14 to point at 14.
+// The dial is rotated L82 to point at 32.
+// Because the dial points at 0 a total of three times during this process, the password in this example is 3.
+
+// Analyze the rotations in your attached document. What's the actual password to open the door?
+
+use std::io::{self, BufRead};
+
+use crate::debug::Step;
+use crate::lint::{self, FieldError, Problem};
+use crate::model::{self, Model};
+use crate::solution::{Example, Solution};
+use crate::util::generate::Rng;
+use crate::util::input;
+use crate::util::narrate;
+
+pub use aoc_core::dial::{turn_left, turn_left_with_clicks, turn_right, turn_right_with_clicks};
+
+pub const SOLUTION: Solution = Solution {
+    year: 2025,
+    day: 1,
+    title: "Secret Entrance",
+    part_one,
+    part_two,
+    parse: model::parse::<Rotations>,
+    explain,
+    debug: Some(debug),
+    stream: Some(stream),
+    solve_bytes: None,
+    anonymize: Some(anonymize),
+    generate: Some(generate),
+    lint,
+    examples: &[Example {
+        input: include_str!("../../fixtures/day_1/example_1.in"),
+        part_one: Some("3"),
+        part_two: Some("6"),
+    }],
+};
+
+// The input parsed into (direction, distance) pairs, the same shape as
+// EXAMPLE below.
+pub struct Rotations(pub Vec<(u8, i32)>);
+
+impl Model for Rotations {
+    fn parse(rotations: &[String]) -> Self {
+        Rotations(
+            rotations
+                .iter()
+                .map(|rotation| {
+                    let (direction, turn_amount) =
+                        parse_rotation(rotation).unwrap_or_else(|error| {
+                            panic!("Invalid rotation {}: {}", rotation, error.message)
+                        });
+                    (direction.as_bytes()[0], turn_amount)
+                })
+                .collect(),
+        )
+    }
+
+    fn solve(&self, part: u8) -> String {
+        match part {
+            1 => count_times_zero(&self.0, &mut |_| {}),
+            _ => count_zero_clicks(&self.0, &mut |_| {}),
+        }
+        .to_string()
+    }
+}
+
+// Reported to the explain hook after every rotation.
+pub struct Rotated {
+    pub direction: u8,
+    pub distance: i32,
+    pub position: i32,
+    // times the dial pointed at 0, including landing on it
+    pub zero_clicks: i32,
+}
+
+impl Rotated {
+    // the rotation as the input wrote it, e.g. L68
+    pub fn rotation(&self) -> String {
+        format!("{}{}", self.direction as char, self.distance)
+    }
+}
+
+// The example rotations from the puzzle text, checked at compile time below.
+const EXAMPLE: [(u8, i32); 10] = [
+    (b'L', 68),
+    (b'L', 30),
+    (b'R', 48),
+    (b'L', 5),
+    (b'R', 60),
+    (b'L', 55),
+    (b'L', 1),
+    (b'L', 99),
+    (b'R', 14),
+    (b'L', 82),
+];
+
+// Because the dial points at 0 a total of three times during this process,
+// the password in this example is 3.
+const _: () = {
+    let mut current_position = 50;
+    let mut times_zero = 0;
+
+    let mut i = 0;
+    while i < EXAMPLE.len() {
+        let (direction, turn_amount) = EXAMPLE[i];
+        current_position = match direction {
+            b'L' => turn_left(current_position, turn_amount),
+            _ => turn_right(current_position, turn_amount),
+        };
+        if current_position == 0 {
+            times_zero += 1;
+        }
+        i += 1;
+    }
+
+    assert!(times_zero == 3);
+};
+
+pub fn part_one(rotations: &[String]) -> String {
+    Rotations::parse(rotations).solve(1)
+}
+
+pub fn count_times_zero(rotations: &[(u8, i32)], on_rotation: &mut impl FnMut(Rotated)) -> i32 {
+    let mut current_position = 50;
+    let mut times_zero = 0;
+
+    for &(direction, turn_amount) in rotations {
+        match direction {
+            // rem_euclid works but I wanted to use my own fn
+            // "L" => current_num = (current_num - turn_amount).rem_euclid(100),
+            // "R" => current_num = (current_num + turn_amount).rem_euclid(100),
+            b'L' => current_position = turn_left(current_position, turn_amount),
+            b'R' => current_position = turn_right(current_position, turn_amount),
+            _ => unreachable!("parse_rotation only allows L and R"),
+        }
+
+        if current_position == 0 {
+            times_zero += 1;
+        }
+
+        on_rotation(Rotated {
+            direction,
+            distance: turn_amount,
+            position: current_position,
+            zero_clicks: (current_position == 0) as i32,
+        });
+    }
+
+    times_zero
+}
+
+// You're sure that's the right password, but the door won't open. You knock, but nobody answers.
+// You build a snowman while you think. As you're rolling the snowballs for your snowman,
+// you find another security document that must have fallen into the snow:
+
+// "Due to newer security protocols, please use password method 0x434C49434B until further notice."
+
+// You remember from the training seminar that "method 0x434C49434B" means you're actually
+// supposed to count the number of times any click causes the dial to point at 0, regardless of
+// whether it happens during a rotation or at the end of one.
+
+// Following the same rotations as in the above example, the dial points at zero a few extra times during its rotations:
+
+// The dial starts by pointing at 50.
+// The dial is rotated L68 to point at 82; during this rotation, it points at 0 once.
+// The dial is rotated L30 to point at 52.
+// The dial is rotated R48 to point at 0.
+// The dial is rotated L5 to point at 95.
+// The dial is rotated R60 to point at 55; during this rotation, it points at 0 once.
+// The dial is rotated L55 to point at 0.
+// The dial is rotated L1 to point at 99.
+// The dial is rotated L99 to point at 0.
+// The dial is rotated R14 to point at 14.
+// The dial is rotated L82 to point at 32; during this rotation, it points at 0 once.
+// In this example, the dial points at 0 three times at the end of a rotation, plus three more times during a rotation. So, in this example, the new password would be 6.
+
+// Be careful: if the dial were pointing at 50, a single rotation like R1000 would cause the dial to point at 0 ten times before returning back to 50!
+
+// Using password method 0x434C49434B, what is the password to open the door?
+
+// In this example, the dial points at 0 three times at the end of a rotation,
+// plus three more times during a rotation. So the new password would be 6.
+const _: () = {
+    let mut current_position = 50;
+    let mut times_zero = 0;
+
+    let mut i = 0;
+    while i < EXAMPLE.len() {
+        let (direction, turn_amount) = EXAMPLE[i];
+        let (new_position, zero_clicks) = match direction {
+            b'L' => turn_left_with_clicks(current_position, turn_amount),
+            _ => turn_right_with_clicks(current_position, turn_amount),
+        };
+        current_position = new_position;
+        times_zero += zero_clicks;
+        i += 1;
+    }
+
+    assert!(times_zero == 6);
+};
+
+// The dial is rotated L68 to point at 82; during this rotation, it points at 0 once.
+const _: () = assert!(matches!(turn_left_with_clicks(50, 68), (82, 1)));
+// R1000 would cause the dial to point at 0 ten times before returning back to 50
+const _: () = assert!(matches!(turn_right_with_clicks(50, 1000), (50, 10)));
+
+pub fn part_two(rotations: &[String]) -> String {
+    Rotations::parse(rotations).solve(2)
+}
+
+pub fn count_zero_clicks(rotations: &[(u8, i32)], on_rotation: &mut impl FnMut(Rotated)) -> i32 {
+    let mut current_position = 50;
+    let mut times_zero = 0;
+
+    for &(direction, turn_amount) in rotations {
+        let times_zero_before = times_zero;
+
+        match direction {
+            // rem_euclid works but I wanted to use my own fn
+            // "L" => current_num = (current_num - turn_amount).rem_euclid(100),
+            // "R" => current_num = (current_num + turn_amount).rem_euclid(100),
+            b'L' => {
+                let (new_position, zero_clicks) =
+                    turn_left_with_clicks(current_position, turn_amount);
+                current_position = new_position;
+                times_zero += zero_clicks;
+            }
+            b'R' => {
+                let (new_position, zero_clicks) =
+                    turn_right_with_clicks(current_position, turn_amount);
+                current_position = new_position;
+                times_zero += zero_clicks;
+            }
+            _ => unreachable!("parse_rotation only allows L and R"),
+        }
+
+        on_rotation(Rotated {
+            direction,
+            distance: turn_amount,
+            position: current_position,
+            zero_clicks: times_zero - times_zero_before,
+        });
+    }
+
+    times_zero
+}
+
+// Reports every rotation to the debugger, with the dial call that made it.
+pub fn debug(rotations: &[String], part: u8, on_step: &mut dyn FnMut(Step)) -> String {
+    let mut line = 0;
+    let mut from = 50;
+    let mut total = 0;
+
+    let rotations = Rotations::parse(rotations);
+    let mut on_rotation = |rotated: Rotated| {
+        let distance = rotated.distance;
+        let turn = if rotated.direction == b'L' {
+            "turn_left"
+        } else {
+            "turn_right"
+        };
+        let call = if part == 1 {
+            format!("{}({}, {}) = {}", turn, from, distance, rotated.position)
+        } else {
+            format!(
+                "{}_with_clicks({}, {}) = ({}, {})",
+                turn, from, distance, rotated.position, rotated.zero_clicks
+            )
+        };
+
+        line += 1;
+        total += rotated.zero_clicks;
+        on_step(
+            Step::new(format!("line {}: {}", line, call))
+                .value("line", line)
+                .value("from", from as i64)
+                .value("distance", distance as i64)
+                .value("position", rotated.position as i64)
+                .value("zero_clicks", rotated.zero_clicks as i64)
+                .value("total", total as i64),
+        );
+        from = rotated.position;
+    };
+
+    let password = if part == 1 {
+        count_times_zero(&rotations.0, &mut on_rotation)
+    } else {
+        count_zero_clicks(&rotations.0, &mut on_rotation)
+    };
+
+    password.to_string()
+}
+
+// Narrates the rotations the way the puzzle walks through its example.
+pub fn explain(rotations: &[String], part: u8, narrate: &mut dyn FnMut(String)) -> String {
+    let rotations = Rotations::parse(rotations);
+    narrate(String::from("The dial starts by pointing at 50."));
+
+    let password = if part == 1 {
+        count_times_zero(&rotations.0, &mut |step| {
+            narrate(format!(
+                "The dial is rotated {} to point at {}.",
+                step.rotation(),
+                step.position
+            ))
+        })
+    } else {
+        count_zero_clicks(&rotations.0, &mut |step| {
+            // landing on 0 is mentioned by the position, only the clicks on the way are extra
+            let during = step.zero_clicks - (step.position == 0) as i32;
+            if during > 0 {
+                narrate(format!(
+                    "The dial is rotated {} to point at {}; during this rotation, it points at 0 {}.",
+                    step.rotation(),
+                    step.position,
+                    narrate::times(during as usize)
+                ))
+            } else {
+                narrate(format!(
+                    "The dial is rotated {} to point at {}.",
+                    step.rotation(),
+                    step.position
+                ))
+            }
+        })
+    };
+
+    narrate(format!(
+        "The dial points at 0 a total of {}, so the password is {}.",
+        narrate::count(password as usize, "time", "times"),
+        password
+    ));
+
+    password.to_string()
+}
+
+const ROTATION_FORMAT: &str = "L or R followed by a distance, like L68";
+
+// Splits a rotation like L68 into its direction and distance.
+pub fn parse_rotation(rotation: &str) -> Result<(&str, i32), FieldError> {
+    let direction = match rotation.chars().next() {
+        Some('L' | 'R') => &rotation[..1],
+        Some(direction) => {
+            return Err(FieldError {
+                offset: 0,
+                message: format!("expected L or R, found '{}'", direction),
+                hint: Some("direction must be L or R"),
+            });
+        }
+        None => {
+            return Err(FieldError {
+                offset: 0,
+                message: String::from("expected a rotation, found an empty line"),
+                hint: None,
+            });
+        }
+    };
+
+    let distance = lint::check_number(&rotation[1..]).map_err(|error| FieldError {
+        offset: error.offset + 1,
+        message: format!("distance: {}", error.message),
+        ..error
+    })?;
+    let distance = i32::try_from(distance).map_err(|_| FieldError {
+        offset: 1,
+        message: format!("distance {} is too large", distance),
+        hint: None,
+    })?;
+
+    Ok((direction, distance))
+}
+
+// Every line is a rotation: L or R followed by a distance.
+pub fn lint(rotations: &[String]) -> Vec<Problem> {
+    rotations
+        .iter()
+        .enumerate()
+        .filter_map(|(index, rotation)| parse_rotation(rotation).err().map(|error| (index, error)))
+        .map(|(index, error)| Problem::field(index + 1, 1, error).expected(ROTATION_FORMAT))
+        .collect()
+}
+
+// Same as part_one and part_two, turning the dial one rotation at a time
+// instead of holding every rotation in memory.
+pub fn stream(reader: &mut dyn BufRead, part: u8) -> io::Result<String> {
+    let mut current_position = 50;
+    let mut times_zero = 0;
+
+    input::stream_lines(reader, |number, rotation| {
+        let (direction, turn_amount) =
+            parse_rotation(rotation).map_err(|error| input::invalid_line(number, error.message))?;
+
+        let (new_position, zero_clicks) = match direction {
+            "L" => turn_left_with_clicks(current_position, turn_amount),
+            _ => turn_right_with_clicks(current_position, turn_amount),
+        };
+        current_position = new_position;
+
+        // part one only counts the rotations that end on 0
+        times_zero += match part {
+            1 => (current_position == 0) as i32,
+            _ => zero_clicks,
+        };
+
+        Ok(true)
+    })?;
+
+    Ok(times_zero.to_string())
+}
+
+// Mirrors every rotation, L for R and back, which leaves the dial as far
+// from 0 as it was after each one since it starts on 50 either way. Some
+// rotations are also split in two, never at 0, so the lines don't line up
+// with the original's either. Neither changes an answer.
+pub fn anonymize(rotations: &[String], rng: &mut Rng) -> Vec<String> {
+    let mut current_position = 50;
+    let mut anonymized = Vec::with_capacity(rotations.len());
+
+    for (direction, turn_amount) in Rotations::parse(rotations).0 {
+        let (direction, turn): (char, fn(i32, i32) -> i32) = match direction {
+            b'L' => ('R', turn_right),
+            _ => ('L', turn_left),
+        };
+
+        let mut remaining = turn_amount;
+        if turn_amount >= 2 && rng.chance(25) {
+            let first = rng.range(1, turn_amount as i64 - 1) as i32;
+            // stopping on 0 would count for part one
+            if turn(current_position, first) != 0 {
+                anonymized.push(format!("{}{}", direction, first));
+                remaining -= first;
+            }
+        }
+        anonymized.push(format!("{}{}", direction, remaining));
+        current_position = turn(current_position, turn_amount);
+    }
+
+    anonymized
+}
+
+// About `size` bytes of rotations of up to 999 clicks, like the real ones.
+pub fn generate(size: usize, rng: &mut Rng) -> Vec<String> {
+    let mut rotations = Vec::new();
+    let mut length = 0;
+    while length < size {
+        let direction = if rng.chance(50) { 'L' } else { 'R' };
+        let rotation = format!("{}{}", direction, rng.range(1, 999));
+        length += rotation.len() + 1;
+        rotations.push(rotation);
+    }
+    rotations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::fixtures::{self, fixture_tests};
+    use crate::util::snapshot::assert_snapshot;
+
+    fixture_tests!(SOLUTION);
+
+    fn example() -> Vec<String> {
+        fixtures::lines(1, "example_1")
+    }
+
+    // the compile-time checks run on their own copy of the example
+    #[test]
+    fn test_const_example_matches_fixture() {
+        let rotations = EXAMPLE
+            .iter()
+            .map(|(direction, distance)| format!("{}{}", *direction as char, distance))
+            .collect::<Vec<String>>();
+        assert_eq!(rotations, example());
+    }
+
+    #[test]
+    fn test_explain() {
+        let (answer, narration) = narrate::collect(explain, &example(), 1);
+        assert_eq!(answer, part_one(&example()));
+        assert_snapshot("2025/day_1/explain_part_one", &narration);
+
+        let (answer, narration) = narrate::collect(explain, &example(), 2);
+        assert_eq!(answer, part_two(&example()));
+        assert_snapshot("2025/day_1/explain_part_two", &narration);
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut steps = Vec::new();
+        let answer = debug(&example(), 2, &mut |step| steps.push(step));
+        assert_eq!(answer, part_two(&example()));
+        assert_eq!(steps.len(), 10);
+        assert_eq!(
+            steps[0].label,
+            "line 1: turn_left_with_clicks(50, 68) = (82, 1)"
+        );
+        assert_eq!(steps[9].values.last(), Some(&("total", 6)));
+
+        let mut steps = Vec::new();
+        debug(&example(), 1, &mut |step| steps.push(step));
+        assert_eq!(steps[2].label, "line 3: turn_right(52, 48) = 0");
+    }
+
+    #[test]
+    fn test_turn_left() {
+        assert_eq!(turn_left(50, 10), 40);
+        assert_eq!(turn_left(0, 10), 90);
+
+        assert_eq!(turn_left(50, 68), 82);
+        assert_eq!(turn_left(82, 30), 52);
+        assert_eq!(turn_left(0, 5), 95);
+        assert_eq!(turn_left(55, 55), 0);
+        assert_eq!(turn_left(0, 1), 99);
+        assert_eq!(turn_left(99, 99), 0);
+        assert_eq!(turn_left(14, 82), 32);
+
+        // looping multiple times
+        assert_eq!(turn_left(14, 200), 14);
+    }
+
+    #[test]
+    fn test_turn_right() {
+        assert_eq!(turn_right(50, 10), 60);
+        assert_eq!(turn_right(99, 10), 9);
+        assert_eq!(turn_right(50, 68), 18);
+        assert_eq!(turn_right(82, 30), 12);
+        assert_eq!(turn_right(0, 5), 5);
+        assert_eq!(turn_right(55, 55), 10);
+        assert_eq!(turn_right(0, 1), 1);
+        assert_eq!(turn_right(99, 99), 98);
+        assert_eq!(turn_right(14, 82), 96);
+        assert_eq!(turn_right(52, 48), 0);
+        assert_eq!(turn_right(95, 60), 55);
+        assert_eq!(turn_right(0, 14), 14);
+
+        // looping multiple times]
+        assert_eq!(turn_right(14, 200), 14);
+    }
+
+    #[test]
+    fn test_turn_left_with_clicks() {
+        assert_eq!(turn_left_with_clicks(50, 10), (40, 0));
+        // starting on 0
+        assert_eq!(turn_left_with_clicks(0, 10), (90, 0), "starting on 0");
+        // landing on 0
+        assert_eq!(turn_left_with_clicks(1, 1), (0, 1), "landing on 0");
+        // landing on 0 after wrapping
+        assert_eq!(
+            turn_left_with_clicks(0, 100),
+            (0, 1),
+            "landing on 0 after wrapping"
+        );
+        assert_eq!(turn_left_with_clicks(0, 0), (0, 0), "not turning");
+        // wrapping around multiple times
+        assert_eq!(
+            turn_left_with_clicks(10, 1000),
+            (10, 10),
+            "wrapping around multiple times"
+        );
+        assert_eq!(
+            turn_left_with_clicks(50, i32::MAX),
+            (3, 21474836),
+            "turning by i32::MAX"
+        );
+    }
+
+    #[test]
+    fn test_turn_right_with_clicks() {
+        assert_eq!(turn_right_with_clicks(50, 10), (60, 0));
+        // looping around once
+        assert_eq!(
+            turn_right_with_clicks(99, 10),
+            (9, 1),
+            "looping around once"
+        );
+        // landing on 0
+        assert_eq!(turn_right_with_clicks(99, 1), (0, 1), "landing on 0");
+        // landing on 0 after wrapping
+        assert_eq!(
+            turn_right_with_clicks(99, 101),
+            (0, 2),
+            "landing on 0 after wrapping"
+        );
+        // wrapping around multiple times
+        assert_eq!(
+            turn_right_with_clicks(10, 1000),
+            (10, 10),
+            "wrapping around multiple times"
+        );
+        // starting on 0
+        assert_eq!(turn_right_with_clicks(0, 10), (10, 0), "starting on 0");
+        assert_eq!(turn_right_with_clicks(0, 100), (0, 1), "a full turn from 0");
+        assert_eq!(turn_right_with_clicks(0, 0), (0, 0), "not turning");
+        // starting on 0 after wrapping
+        assert_eq!(
+            turn_right_with_clicks(0, 101),
+            (1, 1),
+            "starting on 0 after wrapping"
+        );
+        // wrapping around multiple times
+        assert_eq!(
+            turn_right_with_clicks(0, 1000),
+            (0, 10),
+            "wrapping around multiple times"
+        );
+        // 50 + i32::MAX doesn't fit in an i32
+        assert_eq!(
+            turn_right_with_clicks(50, i32::MAX),
+            (97, 21474836),
+            "turning past i32::MAX"
+        );
+        assert_eq!(turn_right(99, i32::MAX), 46, "turning past i32::MAX");
+    }
+
+    #[test]
+    fn test_day_two() {
+        // The dial starts by pointing at 50.
+        // The dial is rotated L68 to point at 82; during this rotation, it points at 0 once.
+        // The dial is rotated L30 to point at 52.
+        // The dial is rotated R48 to point at 0.
+        // The dial is rotated L5 to point at 95.
+        // The dial is rotated R60 to point at 55; during this rotation, it points at 0 once.
+        // The dial is rotated L55 to point at 0.
+        // The dial is rotated L1 to point at 99.
+        // The dial is rotated L99 to point at 0.
+        // The dial is rotated R14 to point at 14.
+        // The dial is rotated L82 to point at 32; during this rotation, it points at 0 once.
+        assert_eq!(
+            turn_left_with_clicks(50, 68),
+            (82, 1),
+            "The dial is rotated L68 to point at 82; during this rotation, it points at 0 once."
+        );
+        assert_eq!(
+            turn_left_with_clicks(82, 30),
+            (52, 0),
+            "The dial is rotated L30 to point at 52."
+        );
+        assert_eq!(
+            turn_right_with_clicks(52, 48),
+            (0, 1),
+            "The dial is rotated R48 to point at 0."
+        );
+        assert_eq!(
+            turn_left_with_clicks(0, 5),
+            (95, 0),
+            "The dial is rotated L5 to point at 95."
+        );
+        assert_eq!(
+            turn_right_with_clicks(95, 60),
+            (55, 1),
+            "The dial is rotated R60 to point at 55; during this rotation, it points at 0 once."
+        );
+        assert_eq!(
+            turn_left_with_clicks(55, 55),
+            (0, 1),
+            "The dial is rotated L55 to point at 0."
+        );
+        assert_eq!(
+            turn_left_with_clicks(0, 1),
+            (99, 0),
+            "The dial is rotated L1 to point at 99."
+        );
+        assert_eq!(
+            turn_left_with_clicks(99, 99),
+            (0, 1),
+            "The dial is rotated L99 to point at 0."
+        );
+        assert_eq!(
+            turn_right_with_clicks(0, 14),
+            (14, 0),
+            "The dial is rotated R14 to point at 14."
+        );
+        assert_eq!(
+            turn_left_with_clicks(14, 82),
+            (32, 1),
+            "The dial is rotated L82 to point at 32; during this rotation, it points at 0 once."
+        );
+
+        // checking wrapping logic
+        assert_eq!(
+            turn_left_with_clicks(50, 1000),
+            (50, 10),
+            "The dial is rotated L1000 to point at 50."
+        );
+        assert_eq!(
+            turn_left_with_clicks(0, 1000),
+            (0, 10),
+            "The dial is rotated L1000 to point at 50."
+        );
+    }
+
+    #[test]
+    fn test_lint() {
+        assert_eq!(lint(&example()), vec![]);
+
+        let input = ["L68", "X30", "R", "", "L5a"].map(String::from);
+        let problems = lint(&input);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| (problem.line, problem.column))
+                .collect::<Vec<(usize, Option<usize>)>>(),
+            vec![(2, Some(1)), (3, Some(2)), (4, Some(1)), (5, Some(3))]
+        );
+        assert_eq!(problems[0].hint, Some("direction must be L or R"));
+    }
+
+    #[test]
+    fn test_stream_matches_batch() {
+        let mut rng = Rng::new(1);
+        let rotations = (0..10_000)
+            .map(|_| {
+                let direction = if rng.chance(50) { 'L' } else { 'R' };
+                format!("{}{}", direction, rng.range(1, 1_000))
+            })
+            .collect::<Vec<String>>();
+
+        for input in [example(), rotations] {
+            let text = input.join("\n");
+            assert_eq!(stream(&mut text.as_bytes(), 1).unwrap(), part_one(&input));
+            assert_eq!(stream(&mut text.as_bytes(), 2).unwrap(), part_two(&input));
+        }
+
+        let error = stream(&mut "L68\nX30\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected L or R, found 'X'");
+    }
+
+    #[test]
+    fn test_anonymize_keeps_the_answers() {
+        let mut rng = Rng::new(7);
+        let rotations = (0..2_000)
+            .map(|_| {
+                let direction = if rng.chance(50) { 'L' } else { 'R' };
+                format!("{}{}", direction, rng.range(1, 300))
+            })
+            .collect::<Vec<String>>();
+
+        // a full turn from 0 clicks past it once whichever way it goes
+        let full_turn = vec![String::from("L50"), String::from("L100")];
+
+        for input in [example(), rotations, full_turn] {
+            let anonymized = anonymize(&input, &mut rng);
+            assert_ne!(anonymized, input);
+            assert_eq!(lint(&anonymized), vec![]);
+            assert_eq!(part_one(&anonymized), part_one(&input));
+            assert_eq!(part_two(&anonymized), part_two(&input));
+        }
+    }
+}
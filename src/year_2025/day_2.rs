@@ -0,0 +1,558 @@
+// --- Day 2: Gift Shop ---
+
+use crate::lint::{self, Problem};
+use crate::model::{self, Model};
+use crate::solution::{Example, Solution};
+use crate::util::generate::Rng;
+use crate::util::narrate;
+use crate::util::ranges::parse_range;
+
+use aoc_core::ids::sum_invalid_ids;
+
+pub use aoc_core::ids::{is_valid_id_arithmetic, is_valid_id_part_two_arithmetic};
+
+pub const SOLUTION: Solution = Solution {
+    year: 2025,
+    day: 2,
+    title: "Gift Shop",
+    part_one,
+    part_two,
+    parse: model::parse::<IdRanges>,
+    explain,
+    debug: None,
+    stream: None,
+    solve_bytes: None,
+    anonymize: None,
+    generate: Some(generate),
+    lint,
+    examples: &[Example {
+        input: include_str!("../../fixtures/day_2/example_1.in"),
+        part_one: Some("1227775554"),
+        part_two: Some("4174379265"),
+    }],
+};
+
+// You get inside and take the elevator to its only other stop: the gift shop.
+// "Thank you for visiting the North Pole!" gleefully exclaims a nearby sign.
+// You aren't sure who is even allowed to visit the North Pole, but you know
+// you can access the lobby through here, and from there you can access the rest of the North Pole base.
+
+// As you make your way through the surprisingly extensive selection, one of
+// the clerks recognizes you and asks for your help.
+
+// As it turns out, one of the younger Elves was playing on a gift shop computer
+// and managed to add a whole bunch of invalid product IDs to their gift shop database!
+// Surely, it would be no trouble for you to identify the invalid product IDs for them, right?
+
+// They've even checked most of the product ID ranges already; they only have a
+// few product ID ranges (your puzzle input) that you'll need to check. For example:
+
+// 11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+// 1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+// 824824821-824824827,2121212118-2121212124
+// (The ID ranges are wrapped here for legibility; in your input, they appear
+//   on a single long line.)
+
+// The ranges are separated by commas (,); each range gives its first ID and
+// last ID separated by a dash (-).
+
+// Since the young Elf was just doing silly patterns, you can find the invalid
+// IDs by looking for any ID which is made only of some sequence of digits repeated twice.
+//So, 55 (5 twice), 6464 (64 twice), and 123123 (123 twice) would all be invalid IDs.
+
+// None of the numbers have leading zeroes; 0101 isn't an ID at all.
+// (101 is a valid ID that you would ignore.)
+
+// Your job is to find all of the invalid IDs that appear in the given ranges. In the above example:
+
+// 11-22 has two invalid IDs, 11 and 22.
+// 95-115 has one invalid ID, 99.
+// 998-1012 has one invalid ID, 1010.
+// 1188511880-1188511890 has one invalid ID, 1188511885.
+// 222220-222224 has one invalid ID, 222222.
+// 1698522-1698528 contains no invalid IDs.
+// 446443-446449 has one invalid ID, 446446.
+// 38593856-38593862 has one invalid ID, 38593859.
+// The rest of the ranges contain no invalid IDs.
+// Adding up all the invalid IDs in this example produces 1227775554.
+
+// The example ranges from the puzzle text, checked at compile time.
+const EXAMPLE: [(i64, i64); 11] = [
+    (11, 22),
+    (95, 115),
+    (998, 1012),
+    (1188511880, 1188511890),
+    (222220, 222224),
+    (1698522, 1698528),
+    (446443, 446449),
+    (38593856, 38593862),
+    (565653, 565659),
+    (824824821, 824824827),
+    (2121212118, 2121212124),
+];
+
+const fn sum_example(repeated: bool) -> i64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < EXAMPLE.len() {
+        sum += sum_invalid_ids(EXAMPLE[i].0, EXAMPLE[i].1, repeated);
+        i += 1;
+    }
+    sum
+}
+
+// 55 (5 twice), 6464 (64 twice), and 123123 (123 twice) would all be invalid IDs.
+const _: () = assert!(!is_valid_id_arithmetic(55));
+const _: () = assert!(!is_valid_id_arithmetic(6464));
+const _: () = assert!(!is_valid_id_arithmetic(123123));
+// (101 is a valid ID that you would ignore.)
+const _: () = assert!(is_valid_id_arithmetic(101));
+// Adding up all the invalid IDs in this example produces 1227775554.
+const _: () = assert!(sum_example(false) == 1227775554);
+
+pub fn part_one(input: &[String]) -> String {
+    IdRanges::parse(input).solve(1)
+}
+
+// The ranges from parse_input, which both parts check every ID of.
+pub struct IdRanges(pub Vec<Vec<i64>>);
+
+impl Model for IdRanges {
+    fn parse(input: &[String]) -> Self {
+        IdRanges(parse_input(input))
+    }
+
+    fn solve(&self, part: u8) -> String {
+        match part {
+            1 => get_sum_invalid_ids(&self.0),
+            _ => get_sum_invalid_ids_part_two(&self.0),
+        }
+        .to_string()
+    }
+}
+
+// the ranges are all on the first line, separated by commas
+pub fn parse_input(input: &[String]) -> Vec<Vec<i64>> {
+    input
+        .first()
+        .unwrap()
+        .split(',')
+        .map(|range| {
+            let (start, end) = parse_range(range);
+            vec![start, end]
+        })
+        .collect::<Vec<Vec<i64>>>()
+}
+
+// Since the young Elf was just doing silly patterns, you can find the invalid IDs by looking for any ID which is made only of some sequence of digits repeated twice. So, 55 (5 twice), 6464 (64 twice), and 123123 (123 twice) would all be invalid IDs.
+pub fn is_valid_id(id: i64) -> bool {
+    let id_str = id.to_string();
+
+    // strip out the leading 0
+    let id_str = id_str.trim_start_matches('0');
+
+    // if odd length then can't have 2 equal sequences
+    if !id_str.len().is_multiple_of(2) {
+        return true;
+    }
+
+    // split into 2 strings
+    let first_half = id_str[..id_str.len() / 2].to_string();
+    let second_half = id_str[id_str.len() / 2..].to_string();
+
+    // check if the first half is equal to the second half
+    first_half != second_half
+}
+
+pub fn get_invalid_ids(range: &[i64]) -> Vec<i64> {
+    let mut invalid_ids = Vec::new();
+
+    assert!(range.len() == 2, "Range must have 2 numbers");
+
+    let start = range[0];
+    let end = range[1];
+
+    for id in start..=end {
+        if !is_valid_id(id) {
+            invalid_ids.push(id);
+        }
+    }
+
+    invalid_ids
+}
+
+pub fn get_sum_invalid_ids(ranges: &[Vec<i64>]) -> i64 {
+    get_sum_invalid_ids_with(ranges, get_invalid_ids, &mut |_, _| {})
+}
+
+// Sums the invalid IDs found by `get_invalid`, reporting each range and the
+// invalid IDs in it to `on_range` as it goes.
+pub fn get_sum_invalid_ids_with(
+    ranges: &[Vec<i64>],
+    get_invalid: fn(&[i64]) -> Vec<i64>,
+    on_range: &mut impl FnMut(&[i64], &[i64]),
+) -> i64 {
+    let mut sum = 0;
+
+    for range in ranges {
+        let invalid_ids = get_invalid(range);
+        on_range(range, &invalid_ids);
+        for id in invalid_ids {
+            sum += id;
+        }
+    }
+
+    sum
+}
+
+// The clerk quickly discovers that there are still invalid IDs in the ranges in your list. Maybe the young Elf was doing other silly patterns as well?
+
+// Now, an ID is invalid if it is made only of some sequence of digits repeated at least twice. So, 12341234 (1234 two times), 123123123 (123 three times), 1212121212 (12 five times), and 1111111 (1 seven times) are all invalid IDs.
+
+// From the same example as before:
+
+// 11-22 still has two invalid IDs, 11 and 22.
+// 95-115 now has two invalid IDs, 99 and 111.
+// 998-1012 now has two invalid IDs, 999 and 1010.
+// 1188511880-1188511890 still has one invalid ID, 1188511885.
+// 222220-222224 still has one invalid ID, 222222.
+// 1698522-1698528 still contains no invalid IDs.
+// 446443-446449 still has one invalid ID, 446446.
+// 38593856-38593862 still has one invalid ID, 38593859.
+// 565653-565659 now has one invalid ID, 565656.
+// 824824821-824824827 now has one invalid ID, 824824824.
+// 2121212118-2121212124 now has one invalid ID, 2121212121.
+// Adding up all the invalid IDs in this example produces 4174379265.
+
+// What do you get if you add up all of the invalid IDs using these new rules?
+// 12341234 (1234 two times), 123123123 (123 three times), 1212121212 (12 five
+// times), and 1111111 (1 seven times) are all invalid IDs.
+const _: () = assert!(!is_valid_id_part_two_arithmetic(12341234));
+const _: () = assert!(!is_valid_id_part_two_arithmetic(123123123));
+const _: () = assert!(!is_valid_id_part_two_arithmetic(1212121212));
+const _: () = assert!(!is_valid_id_part_two_arithmetic(1111111));
+// Adding up all the invalid IDs in this example produces 4174379265.
+const _: () = assert!(sum_example(true) == 4174379265);
+
+pub fn part_two(input: &[String]) -> String {
+    IdRanges::parse(input).solve(2)
+}
+
+pub fn is_valid_id_part_two(id: i64) -> bool {
+    let id_str = id.to_string();
+
+    // strip out the leading 0
+    let id_str = id_str.trim_start_matches('0');
+    let mut has_repeated_sequence = false;
+
+    let is_odd_length = !id_str.len().is_multiple_of(2);
+    let max_sequences = if is_odd_length {
+        (id_str.len() - 1) / 2
+    } else {
+        id_str.len() / 2
+    };
+
+    for sequence_length in 1..=max_sequences {
+        let is_valid_sequence_length = id_str.len().is_multiple_of(sequence_length);
+
+        if !is_valid_sequence_length {
+            continue;
+        }
+
+        let num_sequences = id_str.len() / sequence_length;
+
+        let mut sequences = Vec::new();
+
+        for i in 0..num_sequences {
+            let sequence = id_str[i * sequence_length..(i + 1) * sequence_length].to_string();
+            sequences.push(sequence);
+        }
+
+        // check all of them
+        let all_sequences_are_the_same = sequences.iter().all(|sequence| sequence == &sequences[0]);
+        if all_sequences_are_the_same {
+            has_repeated_sequence = true;
+            break;
+        }
+    }
+
+    !has_repeated_sequence
+}
+
+pub fn get_invalid_ids_part_two(range: &[i64]) -> Vec<i64> {
+    let mut invalid_ids = Vec::new();
+
+    assert!(range.len() == 2, "Range must have 2 numbers");
+
+    let start = range[0];
+    let end = range[1];
+
+    for id in start..=end {
+        if !is_valid_id_part_two(id) {
+            invalid_ids.push(id);
+        }
+    }
+
+    invalid_ids
+}
+
+pub fn get_sum_invalid_ids_part_two(ranges: &[Vec<i64>]) -> i64 {
+    get_sum_invalid_ids_with(ranges, get_invalid_ids_part_two, &mut |_, _| {})
+}
+
+// Narrates each range the way the puzzle walks through its example.
+pub fn explain(input: &[String], part: u8, narrate: &mut dyn FnMut(String)) -> String {
+    let ranges = parse_input(input);
+    let get_invalid = if part == 1 {
+        get_invalid_ids
+    } else {
+        get_invalid_ids_part_two
+    };
+
+    let sum = get_sum_invalid_ids_with(&ranges, get_invalid, &mut |range, invalid_ids| {
+        if invalid_ids.is_empty() {
+            narrate(format!(
+                "{}-{} contains no invalid IDs.",
+                range[0], range[1]
+            ))
+        } else {
+            narrate(format!(
+                "{}-{} has {}, {}.",
+                range[0],
+                range[1],
+                narrate::count(invalid_ids.len(), "invalid ID", "invalid IDs"),
+                narrate::list(invalid_ids)
+            ))
+        }
+    });
+
+    narrate(format!("Adding up all the invalid IDs produces {}.", sum));
+
+    sum.to_string()
+}
+
+const RANGES_FORMAT: &str = "comma separated ranges on one line, like 11-22,95-115";
+
+// One line of comma separated a-b ranges, each with a <= b.
+pub fn lint(input: &[String]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    let mut column = 1;
+    for (index, range) in input[0].split(',').enumerate() {
+        if let Err(error) = lint::check_range(range) {
+            let mut problem = Problem::field(1, column, error).expected(RANGES_FORMAT);
+            problem.message = format!("range {}: {}", index + 1, problem.message);
+            problems.push(problem);
+        }
+        column += range.chars().count() + 1;
+    }
+
+    for (index, _) in input.iter().enumerate().skip(1) {
+        problems.push(
+            Problem::new(
+                index + 1,
+                String::from("expected all the ranges on the first line"),
+            )
+            .at(1)
+            .expected(RANGES_FORMAT),
+        );
+    }
+
+    problems
+}
+
+// About `size` bytes of ranges of up to 100 IDs each. Both parts check
+// every ID, so longer ranges would measure the ranges' lengths rather than
+// how many there are.
+pub fn generate(size: usize, rng: &mut Rng) -> Vec<String> {
+    let mut ranges = Vec::new();
+    let mut length = 0;
+    while length < size {
+        let start = rng.range(1, 9_999_999_899);
+        let range = format!("{}-{}", start, start + rng.range(0, 99));
+        length += range.len() + 1;
+        ranges.push(range);
+    }
+    vec![ranges.join(",")]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::fixtures::{self, fixture_tests};
+    use crate::util::snapshot::assert_snapshot;
+
+    fixture_tests!(SOLUTION);
+
+    fn example() -> Vec<String> {
+        fixtures::lines(2, "example_1")
+    }
+
+    // the compile-time checks run on their own copy of the example
+    #[test]
+    fn test_const_example_matches_fixture() {
+        let ranges = EXAMPLE
+            .iter()
+            .map(|(start, end)| format!("{}-{}", start, end))
+            .collect::<Vec<String>>();
+        assert_eq!(vec![ranges.join(",")], example());
+    }
+
+    #[test]
+    fn test_explain() {
+        let (answer, narration) = narrate::collect(explain, &example(), 1);
+        assert_eq!(answer, part_one(&example()));
+        assert_snapshot("2025/day_2/explain_part_one", &narration);
+
+        let (answer, narration) = narrate::collect(explain, &example(), 2);
+        assert_eq!(answer, part_two(&example()));
+        assert_snapshot("2025/day_2/explain_part_two", &narration);
+    }
+
+    #[test]
+    fn test_is_valid_id() {
+        assert!(!is_valid_id(11), "11 is an invalid ID");
+        assert!(!is_valid_id(22), "22 is an invalid ID");
+        assert!(!is_valid_id(99), "99 is an invalid ID");
+        assert!(is_valid_id(101), "101 is a valid ID");
+        assert!(!is_valid_id(123123), "123123 is an invalid ID");
+        assert!(is_valid_id(12345), "12345 is a valid ID");
+        assert!(!is_valid_id(6464), "6464 is an invalid ID");
+    }
+
+    #[test]
+    fn test_get_invalid_ids() {
+        assert_eq!(
+            get_invalid_ids(&[11, 22]),
+            vec![11, 22],
+            "11 and 22 are invalid IDs"
+        );
+        assert_eq!(get_invalid_ids(&[95, 115]), vec![99], "99 is an invalid ID");
+        assert_eq!(
+            get_invalid_ids(&[998, 1012]),
+            vec![1010],
+            "1010 is an invalid ID"
+        );
+        assert_eq!(
+            get_invalid_ids(&[1188511880, 1188511890]),
+            vec![1188511885],
+            "1188511885 is an invalid ID"
+        );
+        assert_eq!(
+            get_invalid_ids(&[222220, 222224]),
+            vec![222222],
+            "222222 is an invalid ID"
+        );
+        assert_eq!(
+            get_invalid_ids(&[1698522, 1698528]),
+            vec![],
+            "No invalid IDs"
+        );
+        assert_eq!(
+            get_invalid_ids(&[446443, 446449]),
+            vec![446446],
+            "446446 is an invalid ID"
+        );
+        assert_eq!(
+            get_invalid_ids(&[38593856, 38593862]),
+            vec![38593859],
+            "38593859 is an invalid ID"
+        );
+        assert_eq!(get_invalid_ids(&[565653, 565659]), vec![], "No invalid IDs");
+        assert_eq!(
+            get_invalid_ids(&[824824821, 824824827]),
+            vec![],
+            "No invalid IDs"
+        );
+        assert_eq!(
+            get_invalid_ids(&[2121212118, 2121212124]),
+            vec![],
+            "No invalid IDs"
+        );
+    }
+
+    #[test]
+    fn test_get_sum_invalid_ids() {
+        assert_eq!(
+            get_sum_invalid_ids(&[
+                vec![11, 22],
+                vec![95, 115],
+                vec![998, 1012],
+                vec![1188511880, 1188511890],
+                vec![222220, 222224],
+                vec![1698522, 1698528],
+                vec![446443, 446449],
+                vec![38593856, 38593862],
+                vec![565653, 565659],
+                vec![824824821, 824824827],
+                vec![2121212118, 2121212124]
+            ]),
+            1227775554,
+            "The sum of the invalid IDs is 1227775554"
+        );
+    }
+
+    #[test]
+    fn test_is_valid_id_part_two() {
+        // 12341234 (1234 two times), 123123123 (123 three times), 1212121212 (12 five times), and 1111111 (1 seven times) are all invalid IDs.
+        assert!(!is_valid_id_part_two(12341234), "12341234 is an invalid ID");
+        assert!(
+            !is_valid_id_part_two(123123123),
+            "123123123 is an invalid ID"
+        );
+        assert!(
+            !is_valid_id_part_two(1212121212),
+            "1212121212 is an invalid ID"
+        );
+        assert!(!is_valid_id_part_two(1111111), "1111111 is an invalid ID");
+        assert!(is_valid_id_part_two(12345), "12345 is a valid ID");
+    }
+
+    #[test]
+    fn test_is_valid_id_part_two_arithmetic() {
+        for id in [
+            12341234, 123123123, 1212121212, 1111111, 99, 111, 1010, 565656,
+        ] {
+            assert!(
+                !is_valid_id_part_two_arithmetic(id),
+                "{} is an invalid ID",
+                id
+            );
+        }
+        for id in [12345, 101, 1, 1698522, 1188511880] {
+            assert!(is_valid_id_part_two_arithmetic(id), "{} is a valid ID", id);
+        }
+
+        for id in 0..100_000 {
+            assert_eq!(
+                is_valid_id_arithmetic(id),
+                is_valid_id(id),
+                "{} disagrees with is_valid_id",
+                id
+            );
+            assert_eq!(
+                is_valid_id_part_two_arithmetic(id),
+                is_valid_id_part_two(id),
+                "{} disagrees with is_valid_id_part_two",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_lint() {
+        assert_eq!(lint(&example()), vec![]);
+
+        let input = ["11-22,22-11,,95-".to_string(), "1-2".to_string()];
+        let problems = lint(&input);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| (problem.line, problem.column))
+                .collect::<Vec<(usize, Option<usize>)>>(),
+            vec![(1, Some(10)), (1, Some(13)), (1, Some(17)), (2, Some(1))]
+        );
+        assert!(problems[0].message.starts_with("range 2:"));
+        assert_eq!(problems[0].hint, Some("range end is less than start"));
+    }
+}
@@ -0,0 +1,480 @@
+// --- Day 3: Lobby ---
+// You descend a short staircase, enter the surprisingly vast lobby, and are quickly
+// leared by the security checkpoint. When you get to the main elevators, however,
+// you discover that each one has a red light above it: they're all offline.
+
+// "Sorry about that," an Elf apologizes as she tinkers with a nearby control panel.
+// "Some kind of electrical surge seems to have fried them. I'll try to get them online soon."
+
+// You explain your need to get further underground. "Well, you could at least take
+// the escalator down to the printing department, not that you'd get much further than
+// that without the elevators working. That is, you could if the escalator weren't also offline."
+
+// "But, don't worry! It's not fried; it just needs power. Maybe you can get it
+// running while I keep working on the elevators."
+
+// There are batteries nearby that can supply emergency power to the escalator
+// for just such an occasion. The batteries are each labeled with their joltage
+// rating, a value from 1 to 9. You make a note of their joltage ratings
+// (your puzzle input). For example:
+
+// 987654321111111
+// 811111111111119
+// 234234234234278
+// 818181911112111
+
+// The batteries are arranged into banks; each line of digits in your input corresponds
+// to a single bank of batteries. Within each bank, you need to turn on exactly two batteries;
+// the joltage that the bank produces is equal to the number formed by the digits on the batteries
+// you've turned on. For example, if you have a bank like 12345 and you turn on batteries 2 and 4,
+// the bank would produce 24 jolts. (You cannot rearrange batteries.)
+
+// You'll need to find the largest possible joltage each bank can produce. In the above example:
+
+// In 987654321111111, you can make the largest joltage possible, 98, by turning on the first two batteries.
+// In 811111111111119, you can make the largest joltage possible by turning on the batteries labeled 8 and 9, producing 89 jolts.
+// In 234234234234278, you can make 78 by turning on the last two batteries (marked 7 and 8).
+// In 818181911112111, the largest joltage you can produce is 92.
+
+// The total output joltage is the sum of the maximum joltage from each bank, so in this example,
+// the total output joltage is 98 + 89 + 78 + 92 = 357.
+
+// There are many batteries in front of you. Find the maximum joltage possible from each bank; what is the total output joltage?
+
+use std::io::{self, BufRead};
+
+use crate::lint::{self, Problem};
+use crate::model::{self, Model};
+use crate::solution::{Example, Solution};
+use crate::util::bytes;
+use crate::util::generate::Rng;
+use crate::util::input;
+
+use aoc_core::joltage::{get_joltage_from_digits, get_max_joltage_from_digits};
+
+pub use aoc_core::joltage::{get_joltage_from_bank, get_max_joltage};
+
+pub const SOLUTION: Solution = Solution {
+    year: 2025,
+    day: 3,
+    title: "Lobby",
+    part_one,
+    part_two,
+    parse: model::parse::<Banks>,
+    explain,
+    debug: None,
+    stream: Some(stream),
+    solve_bytes: Some(solve_bytes),
+    anonymize: None,
+    generate: Some(generate),
+    lint,
+    examples: &[Example {
+        input: include_str!("../../fixtures/day_3/example_1.in"),
+        part_one: Some("357"),
+        part_two: Some("3121910778619"),
+    }],
+};
+
+// The example banks from the puzzle text, checked at compile time.
+const EXAMPLE: [&str; 4] = [
+    "987654321111111",
+    "811111111111119",
+    "234234234234278",
+    "818181911112111",
+];
+
+// In 818181911112111, the largest joltage you can produce is 92.
+const _: () = assert!(get_joltage_from_bank(EXAMPLE[3]) == 92);
+// The total output joltage is 98 + 89 + 78 + 92 = 357.
+const _: () = assert!(
+    get_joltage_from_bank(EXAMPLE[0])
+        + get_joltage_from_bank(EXAMPLE[1])
+        + get_joltage_from_bank(EXAMPLE[2])
+        + get_joltage_from_bank(EXAMPLE[3])
+        == 357
+);
+
+pub fn part_one(banks: &[String]) -> String {
+    Banks::parse(banks).solve(1)
+}
+
+// Each bank's joltage ratings as the digits' bytes, which is what the
+// joltage functions walk.
+pub struct Banks(pub Vec<Vec<u8>>);
+
+impl Model for Banks {
+    fn parse(banks: &[String]) -> Self {
+        Banks(banks.iter().map(|bank| bank.as_bytes().to_vec()).collect())
+    }
+
+    fn solve(&self, part: u8) -> String {
+        match part {
+            1 => get_joltage_from_banks(&self.0) as i64,
+            _ => get_joltage_from_banks_part_two(&self.0),
+        }
+        .to_string()
+    }
+}
+
+// --- Part Two ---
+// The escalator doesn't move. The Elf explains that it probably needs
+// more joltage to overcome the static friction of the system and hits
+// the big red "joltage limit safety override" button. You lose count
+// of the number of times she needs to confirm "yes, I'm sure" and
+// decorate the lobby a bit while you wait.
+
+// Now, you need to make the largest joltage by turning on exactly twelve
+// batteries within each bank.
+
+// The joltage output for the bank is still the number formed by the digits
+// of the batteries you've turned on; the only difference is that now there
+// will be 12 digits in each bank's joltage output instead of two.
+
+// Consider again the example from before:
+
+// 987654321111111
+// 811111111111119
+// 234234234234278
+// 818181911112111
+// Now, the joltages are much larger:
+
+// In 987654321111111, the largest joltage can be found by turning on everything
+// except some 1s at the end to produce 987654321111.
+// In the digit sequence 811111111111119, the largest joltage can be found by
+// turning on everything except some 1s, producing 811111111119.
+// In 234234234234278, the largest joltage can be found by turning on everything
+// except a 2 battery, a 3 battery, and another 2 battery near the start to produce 434234234278.
+// In 818181911112111, the joltage 888911112111 is produced by turning on
+// everything except some 1s near the front.
+// The total output joltage is now much larger: 987654321111 + 811111111119
+// + 434234234278 + 888911112111 = 3121910778619.
+
+// In 818181911112111, the joltage 888911112111 is produced.
+const _: () = assert!(get_max_joltage(EXAMPLE[3], 12) == 888911112111);
+// The total output joltage is now much larger: 3121910778619.
+const _: () = assert!(
+    get_max_joltage(EXAMPLE[0], 12)
+        + get_max_joltage(EXAMPLE[1], 12)
+        + get_max_joltage(EXAMPLE[2], 12)
+        + get_max_joltage(EXAMPLE[3], 12)
+        == 3121910778619
+);
+
+pub fn part_two(banks: &[String]) -> String {
+    Banks::parse(banks).solve(2)
+}
+
+// The banks are the input's lines or a parsed Banks, digits either way.
+pub fn get_joltage_from_banks(banks: &[impl AsRef<[u8]>]) -> i32 {
+    get_joltage_from_banks_with(banks, &mut |_, _| {})
+}
+
+// Reports each bank and the joltage it produces to `on_bank`.
+pub fn get_joltage_from_banks_with(
+    banks: &[impl AsRef<[u8]>],
+    on_bank: &mut impl FnMut(&[u8], i64),
+) -> i32 {
+    let mut joltage = 0;
+    for bank in banks {
+        let bank = bank.as_ref();
+        let bank_joltage = get_joltage_from_digits(bank);
+        on_bank(bank, bank_joltage as i64);
+        joltage += bank_joltage;
+    }
+    joltage
+}
+
+pub fn get_joltage_from_bank_part_two(bank: &str) -> i64 {
+    get_max_joltage(bank, 12)
+}
+
+pub fn get_joltage_from_banks_part_two(banks: &[impl AsRef<[u8]>]) -> i64 {
+    get_joltage_from_banks_part_two_with(banks, &mut |_, _| {})
+}
+
+pub fn get_joltage_from_banks_part_two_with(
+    banks: &[impl AsRef<[u8]>],
+    on_bank: &mut impl FnMut(&[u8], i64),
+) -> i64 {
+    let mut joltage = 0;
+    for bank in banks {
+        let bank = bank.as_ref();
+        let bank_joltage = get_max_joltage_from_digits(bank, 12);
+        on_bank(bank, bank_joltage);
+        joltage += bank_joltage;
+    }
+    joltage
+}
+
+// Narrates each bank the way the puzzle walks through its example.
+pub fn explain(banks: &[String], part: u8, narrate: &mut dyn FnMut(String)) -> String {
+    let mut on_bank = |bank: &[u8], joltage: i64| {
+        narrate(format!(
+            "In {}, the largest joltage you can produce is {}.",
+            String::from_utf8_lossy(bank),
+            joltage
+        ))
+    };
+
+    let (joltages, total) = if part == 1 {
+        let mut joltages = Vec::new();
+        let total = get_joltage_from_banks_with(banks, &mut |bank, joltage| {
+            joltages.push(joltage);
+            on_bank(bank, joltage)
+        }) as i64;
+        (joltages, total)
+    } else {
+        let mut joltages = Vec::new();
+        let total = get_joltage_from_banks_part_two_with(banks, &mut |bank, joltage| {
+            joltages.push(joltage);
+            on_bank(bank, joltage)
+        });
+        (joltages, total)
+    };
+
+    let sum = joltages
+        .iter()
+        .map(|joltage| joltage.to_string())
+        .collect::<Vec<String>>()
+        .join(" + ");
+    narrate(format!("The total output joltage is {} = {}.", sum, total));
+
+    total.to_string()
+}
+
+const BANK_FORMAT: &str = "a bank of joltage ratings 1-9, like 987654321111111";
+
+// Every line is a bank of 1-9 joltage ratings, all banks the same size.
+pub fn lint(banks: &[String]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    for (index, bank) in banks.iter().enumerate() {
+        if bank.is_empty() {
+            problems.push(
+                Problem::new(
+                    index + 1,
+                    String::from("expected a bank, found an empty line"),
+                )
+                .at(1)
+                .expected(BANK_FORMAT),
+            );
+        }
+        if let Some((column, bad)) = bank
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !matches!(c, '1'..='9'))
+        {
+            problems.push(
+                Problem::new(
+                    index + 1,
+                    format!("expected a joltage rating, found '{}'", bad),
+                )
+                .at(column + 1)
+                .expected(BANK_FORMAT)
+                .hint("joltage ratings run from 1 to 9, there is no 0"),
+            );
+        }
+    }
+
+    lint::check_width(banks, BANK_FORMAT, &mut problems);
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
+// Same as part_one and part_two, adding up one bank at a time instead of
+// holding every bank in memory.
+pub fn stream(reader: &mut dyn BufRead, part: u8) -> io::Result<String> {
+    let mut joltage = 0;
+
+    input::stream_lines(reader, |number, bank| {
+        if let Some(bad) = bank.chars().find(|c| !matches!(c, '1'..='9')) {
+            return Err(input::invalid_line(
+                number,
+                format!("expected a joltage rating, found '{}'", bad),
+            ));
+        }
+
+        joltage += match part {
+            1 => get_joltage_from_bank(bank) as i64,
+            _ => get_joltage_from_bank_part_two(bank),
+        };
+        Ok(true)
+    })?;
+
+    Ok(joltage.to_string())
+}
+
+// Same as part_one and part_two over the input's bytes, each bank a slice
+// of the input rather than a String.
+pub fn solve_bytes(input: &[u8], part: u8) -> String {
+    let mut joltage = 0;
+    for bank in bytes::lines(input) {
+        joltage += match part {
+            1 => get_joltage_from_digits(bank) as i64,
+            _ => get_max_joltage_from_digits(bank, 12),
+        };
+    }
+    joltage.to_string()
+}
+
+// the real inputs have 200 banks, the generated ones grow the banks instead
+// since a bank's length is what the search for its digits loops over
+const GENERATED_BANKS: usize = 100;
+
+// About `size` bytes of banks of random ratings, at least 12 batteries long
+// so part 2 has enough to turn on.
+pub fn generate(size: usize, rng: &mut Rng) -> Vec<String> {
+    let length = (size / GENERATED_BANKS).max(12);
+    (0..GENERATED_BANKS)
+        .map(|_| {
+            (0..length)
+                .map(|_| char::from(b'0' + rng.range(1, 9) as u8))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::fixtures::{self, fixture_tests};
+    use crate::util::generate::Rng;
+    use crate::util::narrate;
+    use crate::util::snapshot::assert_snapshot;
+
+    fixture_tests!(SOLUTION);
+
+    fn example() -> Vec<String> {
+        fixtures::lines(3, "example_1")
+    }
+
+    // the compile-time checks run on their own copy of the example
+    #[test]
+    fn test_const_example_matches_fixture() {
+        assert_eq!(EXAMPLE.map(String::from).to_vec(), example());
+    }
+
+    #[test]
+    fn test_explain() {
+        let (answer, narration) = narrate::collect(explain, &example(), 1);
+        assert_eq!(answer, part_one(&example()));
+        assert_snapshot("2025/day_3/explain_part_one", &narration);
+
+        let (answer, narration) = narrate::collect(explain, &example(), 2);
+        assert_eq!(answer, part_two(&example()));
+        assert_snapshot("2025/day_3/explain_part_two", &narration);
+    }
+
+    #[test]
+    fn test_get_joltage_from_bank() {
+        assert_eq!(get_joltage_from_bank("987654321111111"), 98);
+        assert_eq!(get_joltage_from_bank("811111111111119"), 89);
+        assert_eq!(get_joltage_from_bank("234234234234278"), 78);
+        assert_eq!(get_joltage_from_bank("818181911112111"), 92);
+    }
+
+    #[test]
+    fn test_get_joltage_from_banks() {
+        assert_eq!(
+            get_joltage_from_banks(&fixtures::lines(3, "example_1")),
+            357
+        );
+    }
+
+    #[test]
+    fn test_get_joltage_from_bank_part_two() {
+        // test less than 12
+        assert_eq!(get_joltage_from_bank_part_two("1234"), 1234);
+
+        // test = 12
+        assert_eq!(get_joltage_from_bank_part_two("123456789012"), 123456789012);
+
+        assert_eq!(
+            get_joltage_from_bank_part_two("1223456789012"),
+            223456789012
+        );
+        assert_eq!(
+            get_joltage_from_bank_part_two("13223456789012"),
+            323456789012
+        );
+        assert_eq!(
+            get_joltage_from_bank_part_two("987654321111111"),
+            987654321111
+        );
+        assert_eq!(
+            get_joltage_from_bank_part_two("811111111111119"),
+            811111111119
+        );
+        assert_eq!(
+            get_joltage_from_bank_part_two("234234234234278"),
+            434234234278
+        );
+        assert_eq!(
+            get_joltage_from_bank_part_two("818181911112111"),
+            888911112111
+        );
+    }
+
+    #[test]
+    fn test_get_joltage_from_banks_part_two() {
+        assert_eq!(
+            get_joltage_from_banks_part_two(&fixtures::lines(3, "example_1")),
+            3121910778619
+        );
+    }
+
+    #[test]
+    fn test_get_max_joltage() {
+        for bank in [
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ] {
+            assert_eq!(get_max_joltage(bank, 2), get_joltage_from_bank(bank) as i64);
+        }
+        assert_eq!(get_max_joltage("818181911112111", 3), 921);
+    }
+
+    #[test]
+    fn test_lint() {
+        assert_eq!(lint(&example()), vec![]);
+
+        let input = ["12345", "12045", "1234", "12345"].map(String::from);
+        let problems = lint(&input)
+            .iter()
+            .map(|problem| (problem.line, problem.column))
+            .collect::<Vec<(usize, Option<usize>)>>();
+        assert_eq!(problems, vec![(2, Some(3)), (3, Some(5))]);
+    }
+
+    #[test]
+    fn test_stream_matches_batch() {
+        let mut rng = Rng::new(3);
+        let banks = (0..1_000)
+            .map(|_| (0..100).map(|_| rng.range(1, 9).to_string()).collect())
+            .collect::<Vec<String>>();
+
+        for input in [example(), banks] {
+            let text = input.join("\n");
+            assert_eq!(stream(&mut text.as_bytes(), 1).unwrap(), part_one(&input));
+            assert_eq!(stream(&mut text.as_bytes(), 2).unwrap(), part_two(&input));
+        }
+
+        assert!(stream(&mut "12345\n12045\n".as_bytes(), 1).is_err());
+    }
+
+    #[test]
+    fn test_solve_bytes_matches_batch() {
+        let mut rng = Rng::new(42);
+        let banks = (0..1_000)
+            .map(|_| (0..100).map(|_| rng.range(1, 9).to_string()).collect())
+            .collect::<Vec<String>>();
+
+        for input in [example(), banks] {
+            let text = input.join("\n");
+            assert_eq!(solve_bytes(text.as_bytes(), 1), part_one(&input));
+            assert_eq!(solve_bytes(text.as_bytes(), 2), part_two(&input));
+        }
+    }
+}
@@ -0,0 +1,508 @@
+// --- Day 4: Printing Department ---
+
+use crate::config;
+use crate::debug::Step;
+use crate::lint::{self, Problem};
+use crate::model::{self, Model};
+use crate::solution::{Example, Solution};
+use crate::util::bytes;
+use crate::util::generate::{self, Rng};
+use crate::util::grid::Grid;
+use crate::util::narrate;
+
+pub const SOLUTION: Solution = Solution {
+    year: 2025,
+    day: 4,
+    title: "Printing Department",
+    part_one,
+    part_two,
+    parse: model::parse::<Rolls>,
+    explain,
+    debug: Some(debug),
+    stream: None,
+    solve_bytes: Some(solve_bytes),
+    anonymize: Some(anonymize),
+    generate: Some(generate),
+    lint,
+    examples: &[Example {
+        input: include_str!("../../fixtures/day_4/example_1.in"),
+        part_one: Some("13"),
+        part_two: Some("43"),
+    }],
+};
+
+// If you can optimize the work the forklifts are doing, maybe they would have
+// time to spare to break through the wall.
+
+// The rolls of paper (@) are arranged on a large grid; the Elves even have a
+// helpful diagram (your puzzle input) indicating where everything is located.
+
+// For example:
+
+// ..@@.@@@@.
+// @@@.@.@.@@
+// @@@@@.@.@@
+// @.@@@@..@.
+// @@.@@@@.@@
+// .@@@@@@@.@
+// .@.@.@.@@@
+// @.@@@.@@@@
+// .@@@@@@@@.
+// @.@.@@@.@.
+// The forklifts can only access a roll of paper if there are fewer than four
+// rolls of paper in the eight adjacent positions. If you can figure out which
+// rolls of paper the forklifts can access, they'll spend less time looking and
+// more time breaking down the wall to the cafeteria.
+
+// In this example, there are 13 rolls of paper that can be accessed by a forklift (marked with x):
+
+// ..xx.xx@x.
+// x@@.@.@.@@
+// @@@@@.x.@@
+// @.@@@@..@.
+// x@.@@@@.@x
+// .@@@@@@@.@
+// .@.@.@.@@@
+// x.@@@.@@@@
+// .@@@@@@@@.
+// x.x.@@@.x.
+// Consider your complete diagram of the paper roll locations.
+// How many rolls of paper can be accessed by a forklift?
+
+pub fn part_one(grid: &[String]) -> String {
+    Rolls::parse(grid).solve(1)
+}
+
+// The diagram as a Grid, so a roll's neighbours are index lookups. Each
+// solve removes rolls from its own copy.
+pub struct Rolls(pub Grid);
+
+impl Model for Rolls {
+    fn parse(grid: &[String]) -> Self {
+        Rolls(Grid::from_lines(grid))
+    }
+
+    fn solve(&self, part: u8) -> String {
+        remove_accessible_rolls(&mut self.0.clone(), part).to_string()
+    }
+}
+
+// Now, the Elves just need help accessing as much of the paper as they can.
+
+// Once a roll of paper can be accessed by a forklift, it can be removed.
+// Once a roll of paper is removed, the forklifts might be able to access
+// more rolls of paper, which they might also be able to remove. How many
+// total rolls of paper could the Elves remove if they keep repeating this process?
+
+// Starting with the same example as above, here is one way you could remove
+// as many rolls of paper as possible, using highlighted @ to indicate that
+// a roll of paper is about to be removed, and using x to indicate that a roll of paper was just removed:
+
+pub fn part_two(grid: &[String]) -> String {
+    Rolls::parse(grid).solve(2)
+}
+
+// Keeps removing accessible rolls until none are left, reporting the grid
+// after each round (removed rolls marked with x) to `on_round`.
+pub fn remove_rolls(grid: &[String], on_round: &mut impl FnMut(&[String], u32)) -> u32 {
+    let (accessible_rolls, count_accessible_rolls) = get_accessible_rolls(grid);
+    on_round(&accessible_rolls, count_accessible_rolls);
+
+    let mut grid = accessible_rolls;
+    let mut count = count_accessible_rolls;
+    let mut count_removed = count_accessible_rolls;
+
+    while count > 0 {
+        let (accessible_rolls, count_accessible_rolls) = get_accessible_rolls(&grid);
+        on_round(&accessible_rolls, count_accessible_rolls);
+
+        grid = accessible_rolls;
+        count = count_accessible_rolls;
+        count_removed += count_accessible_rolls;
+    }
+
+    count_removed
+}
+
+// Reports every round of removals to the debugger, with the grid after it
+// (removed rolls marked with x) as the state.
+pub fn debug(grid: &[String], part: u8, on_step: &mut dyn FnMut(Step)) -> String {
+    let mut round = 0;
+    let mut total = 0;
+
+    let mut on_round = |accessible_rolls: &[String], count: u32| {
+        let remaining = accessible_rolls
+            .iter()
+            .map(|row| row.chars().filter(|&cell| cell == '@').count())
+            .sum::<usize>();
+
+        round += 1;
+        total += count;
+        on_step(
+            Step::new(format!(
+                "round {}: removed {}, {} left",
+                round, count, remaining
+            ))
+            .value("round", round)
+            .value("removed", count as i64)
+            .value("remaining", remaining as i64)
+            .value("total", total as i64)
+            .state(accessible_rolls.join("\n")),
+        );
+    };
+
+    if part == 1 {
+        let (accessible_rolls, count) = get_accessible_rolls(grid);
+        on_round(&accessible_rolls, count);
+        return count.to_string();
+    }
+
+    remove_rolls(grid, &mut on_round).to_string()
+}
+
+// Narrates the removals the way the puzzle walks through its example.
+pub fn explain(grid: &[String], part: u8, narrate: &mut dyn FnMut(String)) -> String {
+    let rolls = |count: u32| narrate::count(count as usize, "roll of paper", "rolls of paper");
+
+    if part == 1 {
+        let (accessible_rolls, count) = get_accessible_rolls(grid);
+        narrate(format!(
+            "There {} {} that can be accessed by a forklift (marked with x):",
+            if count == 1 { "is" } else { "are" },
+            rolls(count)
+        ));
+        for row in accessible_rolls {
+            narrate(row);
+        }
+        return count.to_string();
+    }
+
+    let removed = remove_rolls(grid, &mut |accessible_rolls, count| {
+        if count == 0 {
+            return;
+        }
+        narrate(format!("Remove {}:", rolls(count)));
+        for row in accessible_rolls {
+            narrate(row.clone());
+        }
+    });
+
+    narrate(format!(
+        "Stop once no more rolls of paper are accessible; in total, {} can be removed.",
+        rolls(removed)
+    ));
+
+    removed.to_string()
+}
+
+// "fewer than four rolls of paper in the eight adjacent positions"
+pub const NEIGHBOR_THRESHOLD: u32 = 4;
+
+// The threshold from the config (threshold under [2025.day_4] in aoc.toml,
+// or AOC_2025_DAY_4_THRESHOLD), the puzzle's four otherwise. The config
+// rejects anything that isn't a u32 when it loads.
+pub fn neighbor_threshold() -> u32 {
+    config::get()
+        .day_integer(SOLUTION.year, SOLUTION.day, "threshold")
+        .and_then(|threshold| u32::try_from(threshold).ok())
+        .unwrap_or(NEIGHBOR_THRESHOLD)
+}
+
+fn get_accessible_rolls(grid: &[String]) -> (Vec<String>, u32) {
+    let threshold = neighbor_threshold();
+    let mut accessible_rolls = Vec::new();
+    let mut count_accessible_rolls = 0;
+    for (row_index, row) in grid.iter().enumerate() {
+        let mut string_to_add = String::new();
+        for (i, cell) in row.chars().enumerate() {
+            let is_roll = cell == '@';
+            if is_roll {
+                let count = get_adjacent_cells_count(grid, row_index, i);
+
+                if count < threshold {
+                    count_accessible_rolls += 1;
+                    string_to_add.push('x');
+                } else {
+                    string_to_add.push(cell);
+                }
+            } else {
+                string_to_add.push('.');
+            }
+        }
+        accessible_rolls.push(string_to_add);
+    }
+
+    (accessible_rolls, count_accessible_rolls)
+}
+
+pub fn get_adjacent_cells_count(grid: &[String], row: usize, column: usize) -> u32 {
+    let mut count = 0;
+    let is_first_row = row == 0;
+    let is_last_row = row == grid.len() - 1;
+    let is_first_column = column == 0;
+    let is_last_column = column == grid[row].len() - 1;
+
+    let get_char = |x: usize, y: usize| -> char { grid[x].chars().nth(y).unwrap() };
+
+    let is_roll = |x: usize, y: usize| -> bool { get_char(x, y) == '@' };
+
+    if !is_first_row && !is_first_column && is_roll(row - 1, column - 1) {
+        count += 1;
+    }
+
+    if !is_first_row && !is_last_column && is_roll(row - 1, column + 1) {
+        count += 1;
+    }
+
+    if !is_first_row && is_roll(row - 1, column) {
+        count += 1;
+    }
+
+    if !is_first_column && is_roll(row, column - 1) {
+        count += 1;
+    }
+
+    if !is_last_column && is_roll(row, column + 1) {
+        count += 1;
+    }
+
+    if !is_last_row && !is_first_column && is_roll(row + 1, column - 1) {
+        count += 1;
+    }
+
+    if !is_last_row && !is_last_column && is_roll(row + 1, column + 1) {
+        count += 1;
+    }
+
+    if !is_last_row && is_roll(row + 1, column) {
+        count += 1;
+    }
+
+    count
+}
+
+const ROW_FORMAT: &str = "a row of rolls (@) and empty spaces (.), like ..@@.@@@@.";
+
+// A rectangular grid of rolls (@) and empty space (.).
+pub fn lint(grid: &[String]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    for (index, row) in grid.iter().enumerate() {
+        if let Some((column, bad)) = row
+            .chars()
+            .enumerate()
+            .find(|(_, c)| *c != '@' && *c != '.')
+        {
+            problems.push(
+                Problem::new(index + 1, format!("expected @ or ., found '{}'", bad))
+                    .at(column + 1)
+                    .expected(ROW_FORMAT),
+            );
+        }
+    }
+
+    lint::check_width(grid, ROW_FORMAT, &mut problems);
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
+// Same as part_one and part_two over the input's bytes: the rows go from
+// slices of the input straight into a Grid, with no String per row.
+pub fn solve_bytes(input: &[u8], part: u8) -> String {
+    let rows = bytes::lines(input).collect::<Vec<&[u8]>>();
+    remove_accessible_rolls(&mut Grid::from_rows(&rows), part).to_string()
+}
+
+// Removes the accessible rolls a round at a time until none are left, or
+// after the first round for part one, and returns how many went.
+fn remove_accessible_rolls(grid: &mut Grid, part: u8) -> usize {
+    let threshold = neighbor_threshold();
+    let mut count_removed = 0;
+
+    loop {
+        let mut accessible_rolls = Vec::new();
+        for row in 0..grid.height() {
+            for column in 0..grid.width() {
+                if grid.get(row, column) == b'@'
+                    && grid.count_adjacent(row, column, b'@') < threshold
+                {
+                    accessible_rolls.push((row, column));
+                }
+            }
+        }
+
+        if part == 1 {
+            return accessible_rolls.len();
+        }
+        if accessible_rolls.is_empty() {
+            return count_removed;
+        }
+
+        count_removed += accessible_rolls.len();
+        for (row, column) in accessible_rolls {
+            grid.set(row, column, b'.');
+        }
+    }
+}
+
+// Turns and maybe mirrors the grid, one of the square's seven symmetries
+// other than leaving it be. A roll keeps the same neighbours through all of
+// them, so both answers stay the same. A grid that looks the same after the
+// one picked tries the others in turn, and only one symmetric under all of
+// them comes back unchanged.
+pub fn anonymize(lines: &[String], rng: &mut Rng) -> Vec<String> {
+    let grid = Grid::from_lines(lines);
+    let first = rng.range(1, 7);
+
+    for offset in 0..7 {
+        // 1 to 3 are quarter turns, 4 to 7 a mirror and then 0 to 3 turns
+        let symmetry = (first - 1 + offset) % 7 + 1;
+        let mut candidate = match symmetry >= 4 {
+            true => transformed(&grid, false),
+            false => grid.clone(),
+        };
+        for _ in 0..symmetry % 4 {
+            candidate = transformed(&candidate, true);
+        }
+
+        let candidate = candidate.to_lines();
+        if candidate != lines {
+            return candidate;
+        }
+    }
+
+    lines.to_vec()
+}
+
+// The grid turned a quarter clockwise, or mirrored left to right.
+fn transformed(grid: &Grid, quarter_turn: bool) -> Grid {
+    let (width, height) = match quarter_turn {
+        true => (grid.height(), grid.width()),
+        false => (grid.width(), grid.height()),
+    };
+
+    let mut cells = Vec::with_capacity(width * height);
+    for row in 0..height {
+        for column in 0..width {
+            cells.push(match quarter_turn {
+                true => grid.get(grid.height() - 1 - column, row),
+                false => grid.get(row, grid.width() - 1 - column),
+            });
+        }
+    }
+    Grid::from_bytes(width, height, &cells)
+}
+
+// A square grid of about `size` bytes, 60% rolls like the real ones.
+pub fn generate(size: usize, rng: &mut Rng) -> Vec<String> {
+    let side = size.isqrt().max(1);
+    generate::grid(rng, side, side, 60, '@', '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::fixtures::{self, fixture_tests};
+    use crate::util::generate;
+    use crate::util::snapshot::assert_snapshot;
+
+    fixture_tests!(SOLUTION);
+
+    #[test]
+    fn test_get_accessible_rolls() {
+        let input = fixtures::lines(4, "example_1");
+        let (accessible_rolls, count) = get_accessible_rolls(&input);
+
+        assert_eq!(count, 13);
+        assert_snapshot(
+            "2025/day_4/accessible_rolls",
+            &format!("{}\n", accessible_rolls.join("\n")),
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = fixtures::lines(4, "example_1");
+
+        let (answer, narration) = narrate::collect(explain, &input, 1);
+        assert_eq!(answer, part_one(&input));
+        assert_snapshot("2025/day_4/explain_part_one", &narration);
+
+        let (answer, narration) = narrate::collect(explain, &input, 2);
+        assert_eq!(answer, part_two(&input));
+        assert_snapshot("2025/day_4/explain_part_two", &narration);
+    }
+
+    #[test]
+    fn test_debug() {
+        let input = fixtures::lines(4, "example_1");
+
+        let mut steps = Vec::new();
+        let answer = debug(&input, 2, &mut |step| steps.push(step));
+        assert_eq!(answer, part_two(&input));
+        assert_eq!(
+            steps
+                .iter()
+                .map(|step| step.values[1])
+                .collect::<Vec<(&str, i64)>>(),
+            [13, 12, 7, 5, 2, 1, 1, 1, 1, 0].map(|removed| ("removed", removed))
+        );
+        assert_eq!(steps[9].label, "round 10: removed 0, 28 left");
+    }
+
+    #[test]
+    fn test_lint() {
+        let input = ["..@@.", "@@x.@", "@@@@", "....."].map(String::from);
+        let problems = lint(&input);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| problem.line)
+                .collect::<Vec<usize>>(),
+            vec![2, 3]
+        );
+        assert_eq!(problems[0].message, "expected @ or ., found 'x'");
+        assert_eq!(problems[0].column, Some(3));
+
+        assert_eq!(lint(&[String::from("@.@"), String::from(".@.")]), vec![]);
+    }
+
+    #[test]
+    fn test_solve_bytes_matches_batch() {
+        let example = fixtures::lines(4, "example_1");
+        let generated = generate::grid(&mut Rng::new(4), 60, 40, 65, '@', '.');
+
+        for input in [example, generated] {
+            let text = input.join("\n");
+            assert_eq!(solve_bytes(text.as_bytes(), 1), part_one(&input));
+            assert_eq!(solve_bytes(text.as_bytes(), 2), part_two(&input));
+        }
+    }
+
+    #[test]
+    fn test_transformed() {
+        let grid = Grid::from_lines(&["@..", "..@"].map(String::from));
+        assert_eq!(transformed(&grid, true).to_lines(), vec![".@", "..", "@."]);
+        assert_eq!(transformed(&grid, false).to_lines(), vec!["..@", "@.."]);
+    }
+
+    #[test]
+    fn test_anonymize_keeps_the_answers() {
+        let mut rng = Rng::new(8);
+        let example = fixtures::lines(4, "example_1");
+        let generated = generate::grid(&mut rng, 30, 20, 65, '@', '.');
+
+        for input in [example, generated] {
+            // every seed has to hide the grid, not just most of them
+            for seed in 0..64 {
+                assert_ne!(anonymize(&input, &mut Rng::new(seed)), input);
+            }
+
+            let anonymized = anonymize(&input, &mut rng);
+            assert_ne!(anonymized, input);
+            assert_eq!(lint(&anonymized), vec![]);
+            assert_eq!(part_one(&anonymized), part_one(&input));
+            assert_eq!(part_two(&anonymized), part_two(&input));
+        }
+    }
+}
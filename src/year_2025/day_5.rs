@@ -0,0 +1,646 @@
+// --- Day 5: Cafeteria ---
+
+use std::cmp;
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+
+use crate::lint::{self, Problem};
+use crate::model::{self, Cached, Model};
+use crate::solution::{Example, Solution};
+use crate::util::generate::{self, Rng};
+use crate::util::input;
+use crate::util::narrate;
+use crate::util::ranges::{self, parse_range};
+
+pub const SOLUTION: Solution = Solution {
+    year: 2025,
+    day: 5,
+    title: "Cafeteria",
+    part_one,
+    part_two,
+    parse: model::parse_cached::<Inventory>,
+    explain,
+    debug: None,
+    stream: Some(stream),
+    solve_bytes: None,
+    anonymize: Some(anonymize),
+    generate: Some(generate),
+    lint,
+    examples: &[Example {
+        input: include_str!("../../fixtures/day_5/example_1.in"),
+        part_one: Some("3"),
+        part_two: Some("14"),
+    }],
+};
+
+// The database operates on ingredient IDs. It consists
+// of a list of fresh ingredient ID ranges, a blank line,
+// and a list of available ingredient IDs. For example:
+
+// 3-5
+// 10-14
+// 16-20
+// 12-18
+
+// 1
+// 5
+// 8
+// 11
+// 17
+// 32
+
+// The fresh ID ranges are inclusive: the range 3-5 means that ingredient IDs 3, 4, and 5 are all fresh.
+// The ranges can also overlap; an ingredient ID is fresh if it is in any range.
+
+pub fn part_one(input: &[String]) -> String {
+    Inventory::parse(input).solve(1)
+}
+
+// So that they can stop bugging you when they get new inventory, the
+// Elves would like to know all of the IDs that the fresh ingredient ID
+// ranges consider to be fresh. An ingredient ID is still considered fresh
+// if it is in any range.
+
+// Now, the second section of the database (the available ingredient IDs)
+// is irrelevant. Here are the fresh ingredient ID ranges from the above example:
+
+// 3-5
+// 10-14
+// 16-20
+// 12-18
+// The ingredient IDs that these ranges consider to be fresh are
+// 3, 4, 5, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, and 20.
+// So, in this example, the fresh ingredient ID ranges consider a
+// total of 14 ingredient IDs to be fresh.
+
+pub fn part_two(input: &[String]) -> String {
+    Inventory::parse(input).solve(2)
+}
+
+// The database with the ranges merged and the IDs sorted, so part one is a
+// binary search per ID and part two adds up the merged ranges. Merging and
+// sorting make this the one parse worth caching, written back out in the
+// input's own format.
+#[derive(Debug, PartialEq)]
+pub struct Inventory {
+    // sorted and not overlapping
+    pub fresh_ranges: Vec<(i64, i64)>,
+    // sorted, each ID once
+    pub available_ids: Vec<i64>,
+}
+
+impl Model for Inventory {
+    fn parse(input: &[String]) -> Self {
+        let blank = input
+            .iter()
+            .position(|line| line.is_empty())
+            .unwrap_or(input.len());
+        let fresh_ranges = input[..blank]
+            .iter()
+            .map(|range| parse_range(range))
+            .collect::<Vec<(i64, i64)>>();
+        let mut available_ids = input
+            .get(blank + 1..)
+            .unwrap_or_default()
+            .iter()
+            .map(|id| id.parse::<i64>().unwrap())
+            .collect::<Vec<i64>>();
+        available_ids.sort_unstable();
+        available_ids.dedup();
+
+        Inventory {
+            fresh_ranges: ranges::merge_ranges(&fresh_ranges),
+            available_ids,
+        }
+    }
+
+    fn solve(&self, part: u8) -> String {
+        match part {
+            1 => self
+                .available_ids
+                .iter()
+                .filter(|&&id| {
+                    let index = self.fresh_ranges.partition_point(|range| range.1 < id);
+                    self.fresh_ranges
+                        .get(index)
+                        .is_some_and(|range| range.0 <= id)
+                })
+                .count() as i64,
+            _ => ranges::count_covered(&self.fresh_ranges),
+        }
+        .to_string()
+    }
+}
+
+impl Cached for Inventory {
+    const VERSION: u32 = 1;
+
+    fn encode(&self) -> String {
+        let mut text = String::new();
+        for (start, end) in &self.fresh_ranges {
+            text += &format!("{}-{}\n", start, end);
+        }
+        text.push('\n');
+        for id in &self.available_ids {
+            text += &format!("{}\n", id);
+        }
+        text
+    }
+
+    fn decode(text: &str) -> Option<Self> {
+        // the blank line ends the ranges, and is the whole first line when
+        // there aren't any
+        let lines = text.lines().collect::<Vec<&str>>();
+        let blank = lines.iter().position(|line| line.is_empty())?;
+        let (fresh_ranges, available_ids) = (&lines[..blank], &lines[blank + 1..]);
+
+        Some(Inventory {
+            fresh_ranges: fresh_ranges
+                .iter()
+                .map(|range| {
+                    let (start, end) = range.split_once('-')?;
+                    Some((start.parse().ok()?, end.parse().ok()?))
+                })
+                .collect::<Option<Vec<(i64, i64)>>>()?,
+            available_ids: available_ids
+                .iter()
+                .map(|id| id.parse().ok())
+                .collect::<Option<Vec<i64>>>()?,
+        })
+    }
+}
+
+// The naive merge, kept as the other side of the range_merge bench pair:
+// every range is checked against every unprocessed one until nothing
+// overlaps, quadratic in the number of ranges.
+pub fn parse_input_part_two(fresh_ranges: Vec<(i64, i64)>) -> (i64, Vec<(i64, i64)>) {
+    let mut merged_ranges = Vec::new();
+    let mut count_fresh_ids = 0;
+    let mut processed_ranges = vec![false; fresh_ranges.len()];
+
+    // go through the fresh ranges and check for overlapping ranges
+    for (i, range_a) in fresh_ranges.iter().enumerate() {
+        if processed_ranges[i] {
+            continue;
+        }
+
+        processed_ranges[i] = true;
+        let mut current_range = *range_a;
+
+        loop {
+            let mut found_overlapping_range = false;
+            for (j, range_b) in fresh_ranges.iter().enumerate() {
+                if processed_ranges[j] {
+                    continue;
+                }
+
+                if current_range.0 <= range_b.1 && current_range.1 >= range_b.0 {
+                    found_overlapping_range = true;
+                    processed_ranges[j] = true;
+                    current_range = (
+                        cmp::min(current_range.0, range_b.0),
+                        cmp::max(current_range.1, range_b.1),
+                    );
+                    break;
+                }
+            }
+
+            if !found_overlapping_range {
+                break;
+            }
+        }
+
+        merged_ranges.push(current_range);
+    }
+
+    for range in &merged_ranges {
+        count_fresh_ids += range.1 - range.0 + 1;
+    }
+
+    (count_fresh_ids, merged_ranges)
+}
+
+fn format_range(range: (i64, i64)) -> String {
+    format!("{}-{}", range.0, range.1)
+}
+
+// Narrates the ingredient IDs (part one) or the merged ranges (part two)
+// the way the puzzle walks through its example, from the same Inventory the
+// parts solve so the two can't disagree.
+pub fn explain(input: &[String], part: u8, narrate: &mut dyn FnMut(String)) -> String {
+    let inventory = Inventory::parse(input);
+    let sources = merged_from(input, &inventory.fresh_ranges);
+    let describe = |index: usize| {
+        let merged = inventory.fresh_ranges[index];
+        match sources[index].as_slice() {
+            [_] => format_range(merged),
+            sources => format!(
+                "{}, merged from {}",
+                format_range(merged),
+                narrate::list(
+                    &sources
+                        .iter()
+                        .map(|range| format_range(*range))
+                        .collect::<Vec<String>>()
+                )
+            ),
+        }
+    };
+
+    if part == 1 {
+        let mut fresh = 0;
+        for &id in &inventory.available_ids {
+            let index = inventory.fresh_ranges.partition_point(|range| range.1 < id);
+            match inventory.fresh_ranges.get(index) {
+                Some(range) if range.0 <= id => {
+                    fresh += 1;
+                    narrate(format!(
+                        "Ingredient ID {} is fresh because it falls into range {}.",
+                        id,
+                        describe(index)
+                    ))
+                }
+                _ => narrate(format!(
+                    "Ingredient ID {} is spoiled because it does not fall into any range.",
+                    id
+                )),
+            }
+        }
+        narrate(format!(
+            "So, {} of the available ingredient IDs are fresh.",
+            narrate::number(fresh)
+        ));
+        return fresh.to_string();
+    }
+
+    for (index, &merged) in inventory.fresh_ranges.iter().enumerate() {
+        let size = merged.1 - merged.0 + 1;
+        let covers = narrate::count(size as usize, "ID", "IDs");
+        match sources[index].as_slice() {
+            [_] => narrate(format!(
+                "{} overlaps no other range and covers {}.",
+                format_range(merged),
+                covers
+            )),
+            sources => {
+                let sources = sources
+                    .iter()
+                    .map(|range| format_range(*range))
+                    .collect::<Vec<String>>();
+                narrate(format!(
+                    "{} overlap, merging into {}, which covers {}.",
+                    narrate::list(&sources),
+                    format_range(merged),
+                    covers
+                ))
+            }
+        }
+    }
+
+    let count_fresh_ids = ranges::count_covered(&inventory.fresh_ranges);
+    narrate(format!(
+        "So, the fresh ingredient ID ranges consider a total of {} to be fresh.",
+        narrate::count(count_fresh_ids as usize, "ingredient ID", "ingredient IDs")
+    ));
+
+    count_fresh_ids.to_string()
+}
+
+// The input's own ranges that went into each of `merged`, sorted by start.
+fn merged_from(input: &[String], merged: &[(i64, i64)]) -> Vec<Vec<(i64, i64)>> {
+    let mut sources = vec![Vec::new(); merged.len()];
+    let mut fresh_ranges = input
+        .iter()
+        .take_while(|line| !line.is_empty())
+        .map(|range| parse_range(range))
+        .collect::<Vec<(i64, i64)>>();
+    fresh_ranges.sort_unstable();
+
+    for range in fresh_ranges {
+        // every range lies inside the merged range holding its start
+        let index = merged.partition_point(|merged| merged.1 < range.0);
+        sources[index].push(range);
+    }
+    sources
+}
+
+const RANGE_FORMAT: &str = "a fresh ingredient ID range, like 3-5";
+const ID_FORMAT: &str = "an available ingredient ID, like 17";
+
+// Fresh ranges, exactly one blank line, then the available IDs.
+pub fn lint(input: &[String]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut blank_line = None;
+
+    for (index, line) in input.iter().enumerate() {
+        let number = index + 1;
+
+        if line.is_empty() {
+            match blank_line {
+                None => blank_line = Some(number),
+                Some(first) => problems.push(
+                    Problem::new(
+                        number,
+                        format!(
+                            "expected one blank line, already found one on line {}",
+                            first
+                        ),
+                    )
+                    .at(1)
+                    .expected(ID_FORMAT),
+                ),
+            }
+            continue;
+        }
+
+        let problem = if blank_line.is_none() {
+            lint::check_range(line)
+                .err()
+                .map(|error| Problem::field(number, 1, error).expected(RANGE_FORMAT))
+        } else {
+            lint::check_number(line).err().map(|error| {
+                let mut problem = Problem::field(number, 1, error).expected(ID_FORMAT);
+                problem.message = format!("ingredient ID: {}", problem.message);
+                problem
+            })
+        };
+        problems.extend(problem);
+    }
+
+    if blank_line.is_none() {
+        problems.push(
+            Problem::new(
+                input.len(),
+                String::from("expected a blank line between the ranges and the IDs"),
+            )
+            .hint("the fresh ID ranges come first, then a blank line, then the available IDs"),
+        );
+    }
+
+    problems
+}
+
+// Same as part_one and part_two without holding the whole input: the ranges
+// are kept, merged so each ID is a binary search, and part two stops reading
+// at the blank line. Memory is not bounded for part one, though. Like
+// Inventory, an ID listed twice counts once, which takes remembering every
+// distinct fresh ID seen, so it grows with those (stale IDs and repeats
+// cost nothing).
+pub fn stream(reader: &mut dyn BufRead, part: u8) -> io::Result<String> {
+    let mut fresh_ranges = Vec::new();
+    let mut fresh_ids: Option<FreshIds> = None;
+
+    input::stream_lines(reader, |number, line| {
+        let Some(fresh_ids) = &mut fresh_ids else {
+            if !line.is_empty() {
+                let (start, end) = lint::check_range(line)
+                    .map_err(|error| input::invalid_line(number, error.message))?;
+                fresh_ranges.push((start, end));
+                return Ok(true);
+            }
+
+            fresh_ids = Some(FreshIds::new(ranges::merge_ranges(&fresh_ranges)));
+            return Ok(part == 1);
+        };
+
+        let id =
+            lint::check_number(line).map_err(|error| input::invalid_line(number, error.message))?;
+        fresh_ids.add(id);
+        Ok(true)
+    })?;
+
+    let fresh_ids = fresh_ids.unwrap_or_else(|| FreshIds::new(ranges::merge_ranges(&fresh_ranges)));
+    match part {
+        1 => Ok(fresh_ids.seen.len().to_string()),
+        _ => Ok(ranges::count_covered(&fresh_ids.merged_ranges).to_string()),
+    }
+}
+
+// The distinct fresh IDs among those streamed past, the only ones kept.
+struct FreshIds {
+    merged_ranges: Vec<(i64, i64)>,
+    seen: HashSet<i64>,
+}
+
+impl FreshIds {
+    fn new(merged_ranges: Vec<(i64, i64)>) -> Self {
+        FreshIds {
+            merged_ranges,
+            seen: HashSet::new(),
+        }
+    }
+
+    fn add(&mut self, id: i64) {
+        let index = self.merged_ranges.partition_point(|range| range.1 < id);
+        if self
+            .merged_ranges
+            .get(index)
+            .is_some_and(|range| range.0 <= id)
+        {
+            self.seen.insert(id);
+        }
+    }
+}
+
+// Moves every range and ID up by the same random amount and shuffles both
+// lists. Which IDs are fresh and how many IDs the ranges cover don't depend
+// on either, so the answers stay the same.
+pub fn anonymize(input: &[String], rng: &mut Rng) -> Vec<String> {
+    let blank = input
+        .iter()
+        .position(|line| line.is_empty())
+        .unwrap_or(input.len());
+    let (fresh_ranges, available_ids) = input.split_at(blank);
+    let available_ids = available_ids.get(1..).unwrap_or_default();
+
+    let largest = fresh_ranges
+        .iter()
+        .map(|range| parse_range(range).1)
+        .chain(available_ids.iter().map(|id| id.parse::<i64>().unwrap()))
+        .max()
+        .unwrap_or(0);
+    let offset = rng.range(1, cmp::min(1_000_000_000_000, i64::MAX - largest).max(1));
+
+    let mut fresh_ranges = fresh_ranges
+        .iter()
+        .map(|range| {
+            let (start, end) = parse_range(range);
+            format!("{}-{}", start + offset, end + offset)
+        })
+        .collect::<Vec<String>>();
+    let mut available_ids = available_ids
+        .iter()
+        .map(|id| (id.parse::<i64>().unwrap() + offset).to_string())
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut fresh_ranges);
+    rng.shuffle(&mut available_ids);
+
+    fresh_ranges.push(String::new());
+    fresh_ranges.extend(available_ids);
+    fresh_ranges
+}
+
+// About `size` bytes split evenly between ranges and IDs, with numbers as
+// big as the real ones.
+pub fn generate(size: usize, rng: &mut Rng) -> Vec<String> {
+    let mut input = Vec::new();
+    let mut length = 0;
+    while length < size / 2 {
+        let (start, end) = generate::ranges(rng, 1, 500_000_000_000_000, 5_000_000_000_000)[0];
+        let range = format!("{}-{}", start, end);
+        length += range.len() + 1;
+        input.push(range);
+    }
+
+    input.push(String::new());
+    while length < size {
+        let id = rng.range(1, 505_000_000_000_000).to_string();
+        length += id.len() + 1;
+        input.push(id);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::fixtures::{self, fixture_tests};
+    use crate::util::generate;
+    use crate::util::snapshot::assert_snapshot;
+
+    fixture_tests!(SOLUTION);
+
+    fn example() -> Vec<String> {
+        fixtures::lines(5, "example_1")
+    }
+
+    #[test]
+    fn test_explain() {
+        let (answer, narration) = narrate::collect(explain, &example(), 1);
+        assert_eq!(answer, part_one(&example()));
+        assert_snapshot("2025/day_5/explain_part_one", &narration);
+
+        let (answer, narration) = narrate::collect(explain, &example(), 2);
+        assert_eq!(answer, part_two(&example()));
+        assert_snapshot("2025/day_5/explain_part_two", &narration);
+    }
+
+    #[test]
+    fn test_parse_input_part_two() {
+        let fresh_ranges = vec![(3, 5), (10, 14), (16, 20), (12, 18)];
+        let (count_fresh_ids, merged_ranges) = parse_input_part_two(fresh_ranges);
+        assert_eq!(count_fresh_ids, 14);
+        assert_eq!(merged_ranges, vec![(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_inventory() {
+        let inventory = Inventory::parse(&example());
+        assert_eq!(inventory.fresh_ranges, vec![(3, 5), (10, 20)]);
+        assert_eq!(inventory.available_ids, vec![1, 5, 8, 11, 17, 32]);
+
+        assert_eq!(inventory.encode(), "3-5\n10-20\n\n1\n5\n8\n11\n17\n32\n");
+        assert_eq!(Inventory::decode(&inventory.encode()), Some(inventory));
+        assert_eq!(Inventory::decode("3-5\n10-20\n"), None);
+        assert_eq!(Inventory::decode("3-5\n\nfive\n"), None);
+
+        for (fresh_ranges, available_ids) in
+            [(vec![], vec![]), (vec![], vec![7]), (vec![(3, 5)], vec![])]
+        {
+            let inventory = Inventory {
+                fresh_ranges,
+                available_ids,
+            };
+            assert_eq!(Inventory::decode(&inventory.encode()), Some(inventory));
+        }
+        assert_eq!(Inventory::decode(""), None);
+    }
+
+    #[test]
+    fn test_lint() {
+        assert_eq!(lint(&example()), vec![]);
+
+        let input = ["3-5", "5", "", "1", "", "3-5"].map(String::from);
+        let lines = lint(&input)
+            .iter()
+            .map(|problem| problem.line)
+            .collect::<Vec<usize>>();
+        assert_eq!(lines, vec![2, 5, 6]);
+
+        let problems = lint(&["3-5", "1"].map(String::from));
+        assert_eq!(
+            problems.len(),
+            2,
+            "the ID is not a range, and no blank line"
+        );
+
+        // past i64, which the solvers would panic on
+        let too_large = "10000000000000000000-10000000000000000001\n\n5";
+        let input = too_large.lines().map(String::from).collect::<Vec<String>>();
+        assert_eq!(lint(&input).len(), 1);
+        assert!(stream(&mut too_large.as_bytes(), 1).is_err());
+    }
+
+    #[test]
+    fn test_stream_matches_batch() {
+        let mut rng = Rng::new(5);
+        let mut input = generate::ranges(&mut rng, 200, 1_000_000, 10_000)
+            .iter()
+            .map(|(start, end)| format!("{}-{}", start, end))
+            .collect::<Vec<String>>();
+        input.push(String::new());
+        let mut id = 0;
+        for _ in 0..2_000 {
+            id += rng.range(1, 1_000);
+            input.push(id.to_string());
+            // a repeated ID counts once either way
+            if rng.chance(10) {
+                input.push(id.to_string());
+            }
+        }
+        let repeated = ["3-5", "", "5", "5", "9"].map(String::from).to_vec();
+
+        for input in [example(), input, repeated] {
+            let text = input.join("\n");
+            assert_eq!(stream(&mut text.as_bytes(), 1).unwrap(), part_one(&input));
+            assert_eq!(stream(&mut text.as_bytes(), 2).unwrap(), part_two(&input));
+        }
+
+        let error = stream(&mut "3-5\n\n1\nfive\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 4: expected a digit, found 'f'");
+    }
+
+    #[test]
+    fn test_stream_keeps_only_distinct_fresh_ids() {
+        let mut fresh_ids = FreshIds::new(vec![(3, 5), (10, 20)]);
+        for _ in 0..1_000 {
+            for id in [3, 4, 15] {
+                fresh_ids.add(id);
+            }
+        }
+        for stale in 21..10_000 {
+            fresh_ids.add(stale);
+        }
+
+        assert_eq!(fresh_ids.seen.len(), 3);
+    }
+
+    #[test]
+    fn test_anonymize_keeps_the_answers() {
+        let mut rng = Rng::new(9);
+        let mut input = generate::ranges(&mut rng, 200, 1_000_000, 10_000)
+            .iter()
+            .map(|(start, end)| format!("{}-{}", start, end))
+            .collect::<Vec<String>>();
+        input.push(String::new());
+        input.extend((0..2_000).map(|_| rng.range(1, 1_010_000).to_string()));
+
+        for input in [example(), input] {
+            let anonymized = anonymize(&input, &mut rng);
+            assert_ne!(anonymized, input);
+            assert_eq!(lint(&anonymized), vec![]);
+            assert_eq!(part_one(&anonymized), part_one(&input));
+            assert_eq!(part_two(&anonymized), part_two(&input));
+        }
+    }
+}
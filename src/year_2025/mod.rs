@@ -0,0 +1,29 @@
+use crate::solution::Solution;
+
+// Each day sits behind its own cargo feature so a binary can be built with
+// only some of them, see the [features] table in Cargo.toml.
+#[cfg(feature = "year_2025_day_1")]
+pub mod day_1;
+#[cfg(feature = "year_2025_day_2")]
+pub mod day_2;
+#[cfg(feature = "year_2025_day_3")]
+pub mod day_3;
+#[cfg(feature = "year_2025_day_4")]
+pub mod day_4;
+#[cfg(feature = "year_2025_day_5")]
+pub mod day_5;
+
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        #[cfg(feature = "year_2025_day_1")]
+        day_1::SOLUTION,
+        #[cfg(feature = "year_2025_day_2")]
+        day_2::SOLUTION,
+        #[cfg(feature = "year_2025_day_3")]
+        day_3::SOLUTION,
+        #[cfg(feature = "year_2025_day_4")]
+        day_4::SOLUTION,
+        #[cfg(feature = "year_2025_day_5")]
+        day_5::SOLUTION,
+    ]
+}
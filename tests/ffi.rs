@@ -0,0 +1,53 @@
+// Compiles tests/ffi/test_ffi.c against the generated header and the cdylib
+// cargo builds alongside the tests, then runs it.
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// target/<profile>/deps, where the cdylib is written
+fn library_dir() -> PathBuf {
+    std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+#[test]
+fn test_c_program() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_dir();
+    let program = library_dir.join("test_ffi_c");
+
+    let compiler = std::env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    let compiled = Command::new(&compiler)
+        .arg(root.join("tests/ffi/test_ffi.c"))
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg("-L")
+        .arg(&library_dir)
+        .arg("-ladvent_of_code_2025")
+        .arg("-o")
+        .arg(&program)
+        .status();
+
+    match compiled {
+        Ok(status) => assert!(status.success(), "compiling test_ffi.c failed"),
+        Err(error) => {
+            eprintln!("skipping, no C compiler ({}): {}", compiler, error);
+            return;
+        }
+    }
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &library_dir)
+        .env("DYLD_LIBRARY_PATH", &library_dir)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
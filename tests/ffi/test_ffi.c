@@ -0,0 +1,116 @@
+/* Exercises the C library against the worked examples from the puzzles. */
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "advent_of_code_2025.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                              \
+    do {                                                              \
+        if (!(condition)) {                                           \
+            fprintf(stderr, "%s:%d: failed: %s\n", __FILE__, __LINE__, \
+                    #condition);                                      \
+            failures++;                                               \
+        }                                                             \
+    } while (0)
+
+static void test_dial(void) {
+    AocDialTurn turn = aoc_turn_left_with_clicks(50, 68);
+    CHECK(turn.position == 82 && turn.zero_clicks == 1);
+
+    turn = aoc_turn_right_with_clicks(95, 60);
+    CHECK(turn.position == 55 && turn.zero_clicks == 1);
+
+    turn = aoc_turn_right_with_clicks(50, 1000);
+    CHECK(turn.position == 50 && turn.zero_clicks == 10);
+
+    /* 50 + INT32_MAX is past INT32_MAX but still turns */
+    turn = aoc_turn_right_with_clicks(50, INT32_MAX);
+    CHECK(turn.position == 97 && turn.zero_clicks == 21474836);
+    turn = aoc_turn_left_with_clicks(50, INT32_MAX);
+    CHECK(turn.position == 3 && turn.zero_clicks == 21474836);
+    turn = aoc_turn_left_with_clicks(100, 5);
+    CHECK(turn.position == -1);
+    turn = aoc_turn_left_with_clicks(50, -5);
+    CHECK(turn.position == -1);
+}
+
+static void test_ids(void) {
+    CHECK(aoc_is_doubled_id(6464));
+    CHECK(!aoc_is_doubled_id(101));
+    CHECK(!aoc_is_doubled_id(123123123));
+    CHECK(aoc_is_repeated_id(123123123));
+    CHECK(aoc_is_repeated_id(1111111));
+    CHECK(!aoc_is_repeated_id(12345));
+    /* 19 digits */
+    CHECK(aoc_is_repeated_id(1111111111111111111LL));
+    CHECK(!aoc_is_repeated_id(INT64_MAX));
+}
+
+static void test_joltage(void) {
+    const char *bank = "818181911112111";
+    CHECK(aoc_max_joltage((const uint8_t *)bank, strlen(bank), 2) == 92);
+    CHECK(aoc_max_joltage((const uint8_t *)bank, strlen(bank), 12) == 888911112111LL);
+    CHECK(aoc_max_joltage(NULL, 0, 2) == -1);
+    CHECK(aoc_max_joltage((const uint8_t *)"12a4", 4, 2) == -1);
+    /* longer than any slice can be */
+    CHECK(aoc_max_joltage((const uint8_t *)bank, SIZE_MAX, 2) == -1);
+}
+
+static void test_rolls(void) {
+    const char *grid =
+        "..@@.@@@@."
+        "@@@.@.@.@@"
+        "@@@@@.@.@@"
+        "@.@@@@..@."
+        "@@.@@@@.@@"
+        ".@@@@@@@.@"
+        ".@.@.@.@@@"
+        "@.@@@.@@@@"
+        ".@@@@@@@@."
+        "@.@.@@@.@.";
+    CHECK(aoc_count_accessible_rolls((const uint8_t *)grid, 10, 10) == 13);
+    CHECK(aoc_count_accessible_rolls(NULL, 10, 10) == -1);
+    /* width * height overflows */
+    CHECK(aoc_count_accessible_rolls((const uint8_t *)grid, SIZE_MAX / 2, 3) == -1);
+}
+
+static void test_ranges(void) {
+    AocRange ranges[] = {{3, 5}, {10, 14}, {16, 20}, {12, 18}};
+    CHECK(aoc_count_fresh_ids(ranges, 4) == 14);
+
+    AocRange everything[] = {{-1, INT64_MAX}};
+    CHECK(aoc_count_fresh_ids(everything, 1) == -1);
+    AocRange backwards[] = {{5, 3}};
+    CHECK(aoc_count_fresh_ids(backwards, 1) == -1);
+    CHECK(aoc_count_fresh_ids(ranges, SIZE_MAX / 4) == -1);
+
+    /* SIZE_MAX for a failure, so it can't be mistaken for no ranges */
+    CHECK(aoc_merge_ranges(NULL, 4) == SIZE_MAX);
+    CHECK(aoc_merge_ranges(backwards, 1) == SIZE_MAX);
+    CHECK(backwards[0].start == 5 && backwards[0].end == 3);
+    CHECK(aoc_merge_ranges(ranges, SIZE_MAX / 4) == SIZE_MAX);
+    CHECK(aoc_merge_ranges(ranges, 0) == 0);
+
+    size_t merged = aoc_merge_ranges(ranges, 4);
+    CHECK(merged == 2);
+    CHECK(ranges[0].start == 3 && ranges[0].end == 5);
+    CHECK(ranges[1].start == 10 && ranges[1].end == 20);
+}
+
+int main(void) {
+    test_dial();
+    test_ids();
+    test_joltage();
+    test_rolls();
+    test_ranges();
+
+    if (failures > 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}